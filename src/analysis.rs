@@ -0,0 +1,246 @@
+//! This module provides helpers for analysing and quality-checking fetched feed data.
+
+use crate::{
+    models::feed::Item,
+    time::{Clock, SystemClock},
+};
+
+/// The earliest plausible transaction timestamp (the Bitcoin genesis block, January 2009).
+const MIN_TIMESTAMP: u64 = 1_230_940_800;
+/// How far in the future, in seconds, a timestamp may be before it is considered invalid.
+const MAX_FUTURE_SKEW: u64 = 24 * 60 * 60;
+/// Relative tolerance when checking that a USD amount matches `amount * price`.
+const USD_TOLERANCE: f64 = 0.01;
+
+/// An invariant violation found in a feed item by [`validate_feed`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationIssue {
+    /// The position of the offending item in the validated slice.
+    pub position: usize,
+    /// The transaction hash of the offending item.
+    pub tx_hash: String,
+    /// The kind of issue found.
+    pub kind: IssueKind,
+}
+
+/// The kinds of issues detected by [`validate_feed`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum IssueKind {
+    /// The transaction hash is empty.
+    EmptyTxHash,
+    /// An amount or value field is negative.
+    NegativeAmount {
+        /// The name of the offending field.
+        field: &'static str,
+        /// The negative value.
+        value: f64,
+    },
+    /// The timestamp is before 2009 or too far in the future.
+    TimestampOutOfRange(u64),
+    /// The USD amount does not match the token amount multiplied by its USD price.
+    InconsistentUsdAmount {
+        /// The name of the USD amount field.
+        field: &'static str,
+        /// The USD amount reported by the API.
+        reported: f64,
+        /// The USD amount computed from the token amount and price.
+        expected: f64,
+    },
+}
+
+/// Checks a page of feed items for internal consistency.
+///
+/// The following invariants are checked for every item:
+///
+/// * the transaction hash is not empty,
+/// * amount and value fields are not negative,
+/// * the timestamp is after January 2009 and at most a day in the future,
+/// * USD amounts match the token amount multiplied by its USD price, within 1%.
+///   Items with a zero price are skipped, as it usually means the price is unknown.
+///
+/// This never fails; it returns every issue found so callers can decide how to react.
+///
+/// # Examples
+///
+/// ```
+/// use cielo_rs_sdk::analysis::validate_feed;
+///
+/// assert!(validate_feed(&[]).is_empty());
+/// ```
+pub fn validate_feed(items: &[Item]) -> Vec<ValidationIssue> {
+    validate_feed_with_clock(items, &SystemClock)
+}
+
+/// Checks a page of feed items for internal consistency, using the given clock as the
+/// current time when checking timestamps.
+///
+/// See [`validate_feed`] for the invariants checked.
+pub fn validate_feed_with_clock(items: &[Item], clock: &dyn Clock) -> Vec<ValidationIssue> {
+    let now = clock.now();
+
+    let mut issues = Vec::new();
+    for (position, item) in items.iter().enumerate() {
+        let mut report = |kind| {
+            issues.push(ValidationIssue {
+                position,
+                tx_hash: item.tx_hash().to_string(),
+                kind,
+            })
+        };
+
+        if item.tx_hash().is_empty() {
+            report(IssueKind::EmptyTxHash);
+        }
+
+        for (field, value) in amounts(item) {
+            if value < 0.0 {
+                report(IssueKind::NegativeAmount { field, value });
+            }
+        }
+
+        let timestamp = item.timestamp();
+        if timestamp < MIN_TIMESTAMP || timestamp > now + MAX_FUTURE_SKEW {
+            report(IssueKind::TimestampOutOfRange(timestamp));
+        }
+
+        for (field, amount, price, reported) in usd_amounts(item) {
+            if price == 0.0 {
+                continue;
+            }
+            let expected = amount * price;
+            if (expected - reported).abs() > USD_TOLERANCE * expected.abs().max(reported.abs()) {
+                report(IssueKind::InconsistentUsdAmount {
+                    field,
+                    reported,
+                    expected,
+                });
+            }
+        }
+    }
+
+    issues
+}
+
+/// Returns the amount and value fields of an item that are expected to be non-negative.
+fn amounts(item: &Item) -> Vec<(&'static str, f64)> {
+    match item {
+        Item::Swap(swap) => vec![
+            ("token0_amount", swap.token0_amount),
+            ("token0_amount_usd", swap.token0_amount_usd),
+            ("token1_amount", swap.token1_amount),
+            ("token1_amount_usd", swap.token1_amount_usd),
+        ],
+        Item::Lp(lp) => vec![
+            ("token0_amount", lp.token0_amount),
+            ("token0_amount_usd", lp.token0_amount_usd),
+            ("token1_amount", lp.token1_amount),
+            ("token1_amount_usd", lp.token1_amount_usd),
+        ],
+        Item::Transfer(transfer) => vec![("amount_usd", transfer.amount_usd)],
+        Item::Lending(lending) => vec![
+            ("amount", lending.amount),
+            ("amount_usd", lending.amount_usd),
+        ],
+        Item::NftMint(mint) => vec![
+            ("amount", mint.amount),
+            ("value", mint.value),
+            ("value_usd", mint.value_usd),
+        ],
+        Item::NftTrade(trade) => vec![("price", trade.price), ("price_usd", trade.price_usd)],
+        Item::NftTransfer(transfer) => vec![("value", transfer.value)],
+        Item::NftLending(lending) => {
+            vec![("price", lending.price), ("price_usd", lending.price_usd)]
+        }
+        Item::Bridge(bridge) => vec![("amount", bridge.amount), ("amount_usd", bridge.amount_usd)],
+        Item::ContractInteraction(_) => vec![],
+        Item::Wrap(wrap) => vec![("amount", wrap.amount), ("amount_usd", wrap.amount_usd)],
+        Item::SudoPool(pool) => vec![
+            ("token0_amount", pool.token0_amount),
+            ("token0_amount_usd", pool.token0_amount_usd),
+        ],
+        Item::Reward(reward) => vec![("amount", reward.amount), ("amount_usd", reward.amount_usd)],
+        Item::Staking(staking) => vec![
+            ("amount", staking.amount),
+            ("amount_usd", staking.amount_usd),
+        ],
+        Item::Perp(perp) => vec![("amount_usd", perp.amount_usd)],
+        Item::Flashloan(loan) => vec![("amount", loan.amount), ("amount_usd", loan.amount_usd)],
+        Item::ContractCreation(creation) => vec![("amount_usd", creation.amount_usd)],
+        Item::NftLiquidation(liquidation) => vec![
+            ("price", liquidation.price),
+            ("price_usd", liquidation.price_usd),
+        ],
+        Item::Option(option) => vec![("amount", option.amount)],
+        Item::NftSweep(sweep) => vec![("price", sweep.price), ("price_usd", sweep.price_usd)],
+    }
+}
+
+/// Returns the `(field, amount, price_usd, amount_usd)` tuples of an item whose USD amount
+/// should equal the token amount multiplied by its USD price.
+fn usd_amounts(item: &Item) -> Vec<(&'static str, f64, f64, f64)> {
+    match item {
+        Item::Swap(swap) => vec![
+            (
+                "token0_amount_usd",
+                swap.token0_amount,
+                swap.token0_price_usd,
+                swap.token0_amount_usd,
+            ),
+            (
+                "token1_amount_usd",
+                swap.token1_amount,
+                swap.token1_price_usd,
+                swap.token1_amount_usd,
+            ),
+        ],
+        Item::Lp(lp) => vec![
+            (
+                "token0_amount_usd",
+                lp.token0_amount,
+                lp.token0_price_usd,
+                lp.token0_amount_usd,
+            ),
+            (
+                "token1_amount_usd",
+                lp.token1_amount,
+                lp.token1_price_usd,
+                lp.token1_amount_usd,
+            ),
+        ],
+        Item::SudoPool(pool) => vec![(
+            "token0_amount_usd",
+            pool.token0_amount,
+            pool.token0_price_usd,
+            pool.token0_amount_usd,
+        )],
+        Item::Lending(lending) => vec![(
+            "amount_usd",
+            lending.amount,
+            lending.price_usd,
+            lending.amount_usd,
+        )],
+        Item::Bridge(bridge) => {
+            vec![("amount_usd", bridge.amount, bridge.price, bridge.amount_usd)]
+        }
+        Item::Wrap(wrap) => vec![(
+            "amount_usd",
+            wrap.amount,
+            wrap.token_price_usd,
+            wrap.amount_usd,
+        )],
+        Item::Reward(reward) => vec![(
+            "amount_usd",
+            reward.amount,
+            reward.price_usd,
+            reward.amount_usd,
+        )],
+        Item::Staking(staking) => vec![(
+            "amount_usd",
+            staking.amount,
+            staking.token_price_usd,
+            staking.amount_usd,
+        )],
+        Item::Flashloan(loan) => vec![("amount_usd", loan.amount, loan.price_usd, loan.amount_usd)],
+        _ => vec![],
+    }
+}
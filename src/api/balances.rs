@@ -0,0 +1,87 @@
+//! This module provides functionality for interacting with the Cielo balances API.
+//!
+//! It includes structures and methods for querying the current token balances of a wallet.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models,
+    reqwest_ext::{read_json, status_error},
+};
+
+use super::CieloApi;
+
+/// Filters for querying wallet balances.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BalanceFilters {
+    /// Filter balances by specific blockchain chains (e.g., ethereum).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chains: Option<Vec<String>>,
+    /// Set a minimum USD value for balances.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_usd: Option<u64>,
+}
+
+impl CieloApi {
+    /// Fetches the current token balances of a wallet.
+    ///
+    /// # Arguments
+    ///
+    /// * `wallet` - The wallet address to fetch balances for.
+    /// * `filters` - A BalanceFilters struct containing various filter options.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if the request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let balances = cielo_api
+    ///     .get_balances("0x...", api::balances::BalanceFilters::default())
+    ///     .await
+    ///     .unwrap();
+    /// for balance in balances {
+    ///     println!("{}: {} (${:.2})", balance.token_symbol, balance.balance, balance.balance_usd);
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_balances(
+        &self,
+        wallet: &str,
+        filters: BalanceFilters,
+    ) -> Result<Vec<models::balances::TokenBalance>, crate::Error> {
+        let url = format!("{}balances", self.base_url);
+
+        let mut request = self.client.get(url).query(&[("wallet", wallet)]);
+        if let Some(chains) = filters.chains {
+            request = request.query(&[("chains", chains.join(","))]);
+        }
+        if let Some(min_usd) = filters.min_usd {
+            request = request.query(&[("minUSD", min_usd.to_string())]);
+        }
+
+        let response = request.send().await?;
+
+        // Check if the response status is not 200 OK
+        if !response.status().is_success() {
+            return Err(status_error(response, self.max_response_bytes).await);
+        }
+
+        let response: models::Response<models::balances::TokenBalance> =
+            read_json(response, self.max_response_bytes)
+                .await?
+                .unwrap_or_else(models::Response::empty);
+        if self.check_status {
+            response.check_status()?;
+        }
+        if self.strict_responses {
+            response.validate()?;
+        }
+        Ok(response.data.items)
+    }
+}
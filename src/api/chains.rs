@@ -0,0 +1,67 @@
+//! This module provides functionality for querying the chains supported by the Cielo API.
+
+use crate::{
+    models,
+    reqwest_ext::{read_json, status_error},
+};
+
+use super::CieloApi;
+
+impl CieloApi {
+    /// Fetches the identifiers of the chains supported by the API (e.g. `ethereum`).
+    ///
+    /// Unlike [`crate::api::feed::Chain`], which is fixed at compile time, the list is live, so
+    /// it can be used to validate [`crate::api::feed::Filters::chains`] against chains added
+    /// after this version of the SDK. If enabled with
+    /// [`CieloApiBuilder::cache_supported_chains`](super::CieloApiBuilder::cache_supported_chains),
+    /// the first successful result is reused for the lifetime of the client.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if the request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::CieloApi;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let chains = cielo_api.get_supported_chains().await.unwrap();
+    /// assert!(chains.iter().any(|chain| chain == "ethereum"));
+    /// # }
+    /// ```
+    pub async fn get_supported_chains(&self) -> Result<Vec<String>, crate::Error> {
+        if let Some(chains) = self
+            .chains_cache
+            .as_ref()
+            .and_then(|chains_cache| chains_cache.get())
+        {
+            return Ok(chains.clone());
+        }
+
+        let url = format!("{}chains", self.base_url);
+        let response = self.client.get(url).send().await?;
+
+        // Check if the response status is not 200 OK
+        if !response.status().is_success() {
+            return Err(status_error(response, self.max_response_bytes).await);
+        }
+
+        let response: models::Response<String> = read_json(response, self.max_response_bytes)
+            .await?
+            .unwrap_or_else(models::Response::empty);
+        if self.check_status {
+            response.check_status()?;
+        }
+        if self.strict_responses {
+            response.validate()?;
+        }
+
+        if let Some(chains_cache) = &self.chains_cache {
+            // A concurrent call may have filled the cache first, with an equivalent list.
+            let _ = chains_cache.set(response.data.items.clone());
+        }
+        Ok(response.data.items)
+    }
+}
@@ -2,46 +2,534 @@
 //!
 //! It includes structures and methods for querying and filtering feed data.
 
-use crate::{constants, models};
-use strum_macros::Display;
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    convert::Infallible,
+    fmt,
+    ops::RangeInclusive,
+    str::FromStr,
+    sync::Mutex,
+    time::Duration,
+};
+
+use crate::{
+    constants, models,
+    reqwest_ext::{read_bytes, read_json, status_error, AttemptLog, RetryAttempt},
+    time::{Clock, Instant, SystemClock},
+};
+use futures::{
+    future::{self, Either},
+    stream, Future, Stream, StreamExt,
+};
+use futures_timer::Delay;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
 
 use super::CieloApi;
 
+/// The maximum number of pages fetched by [`CieloApi::backfill_until`], guarding against a
+/// cursor that never reaches the target timestamp.
+pub const BACKFILL_MAX_PAGES: usize = 1_000;
+
 /// Filters for querying the feed.
-#[derive(Debug, Clone, Default)]
+///
+/// Each field documents the name of the query parameter it is sent as, matching the
+/// [API reference](https://developer.cielo.finance/reference/getfeed). The names mix camelCase
+/// and lowercase as the API does; in particular, the list ID is sent as `list`, not `listId`.
+///
+/// Filters can be serialized, e.g. to persist presets. Unset fields are omitted.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Filters {
-    /// Filter the feed by a specific wallet address.
+    /// Filter the feed by a specific wallet address. Sent as `wallet`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub wallet: Option<String>,
-    /// Limit the number of transactions returned in the feed. The maximum limit is 100.
+    /// Limit the number of transactions returned in the feed. The maximum limit is 100. Sent as
+    /// `limit`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
-    /// Filter transactions by a specific List ID.
+    /// Filter transactions by a specific List ID. Sent as `list`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub list_id: Option<u64>,
-    /// Filter transactions by specific blockchain chains (e.g., ethereum).
+    /// Filter transactions by specific blockchain chains (e.g., ethereum). Sent as
+    /// comma-separated `chains`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub chains: Option<Vec<String>>,
-    /// Filter transactions by types (e.g., swap, nft_trade).
+    /// Filter transactions by types (e.g., swap, nft_trade). Sent as comma-separated `txTypes`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tx_types: Option<Vec<TxType>>,
     /// Filter transactions by specific tokens, identified by either their address or symbol.
+    /// Sent as comma-separated `tokens`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tokens: Option<Vec<String>>,
-    /// Set a minimum USD value for transactions. Default is 0.
+    /// Set a minimum USD value for transactions. Default is 0. Sent as `minUSD`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub min_usd: Option<u64>,
-    /// Filter transactions by new trades.
+    /// Only return new trades, i.e. the first purchases of a token by the tracked wallets. See
+    /// [`Filters::first_buys_only`]. Sent as `newTrades`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub new_trades: Option<bool>,
-    /// Set value from response 'paging.next_object_id' to get the next page.
+    /// Set value from response 'paging.next_object_id' to get the next page. Sent as `startFrom`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub start_from: Option<String>,
-    /// Filter transactions from a specific UNIX timestamp.
+    /// Skip this many transactions, to jump to an arbitrary page. Mutually exclusive with
+    /// `start_from`: requests with both set fail with [`crate::Error::InvalidFilter`]. The
+    /// paginating methods follow the cursor after the first page, so the offset only applies to
+    /// it, except for [`CieloApi::get_feed_parallel`], which pages by offset. Sent as `offset`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u64>,
+    /// Filter transactions from a specific UNIX timestamp. Sent as `fromTimestamp`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub from_timestamp: Option<u64>,
-    /// Filter transactions to a specific UNIX timestamp.
+    /// Filter transactions to a specific UNIX timestamp. Sent as `toTimestamp`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub to_timestamp: Option<u64>,
-    /// Include marketcap in the response.
+    /// Include marketcap in the response, populating `token_market_cap` on swaps and transfers.
+    /// Sent as `includeMarketCap`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub include_market_cap: Option<bool>,
+    /// The order in which transactions are returned. The API default (newest first) is used when `None`.
+    /// Sent as `sort`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SortOrder>,
+    /// Extra query parameters appended verbatim, to use API parameters not yet supported by the
+    /// SDK. Typed fields take precedence: extra parameters named like one that is already set
+    /// are dropped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extra: Option<Vec<(String, String)>>,
+}
+
+impl Filters {
+    /// Returns the canonical query string identifying the query described by these filters.
+    ///
+    /// The query parameters are sorted by name, with list values (chains, transaction types and
+    /// tokens) sorted and deduplicated, so logically equal queries yield the same string, and
+    /// different queries different strings. Only the `%`, `&` and `=` characters are
+    /// percent-encoded, keeping the string readable while separating parameters unambiguously.
+    pub fn canonical_query(&self) -> String {
+        let mut params = self.to_query_pairs();
+        params.sort();
+
+        let params: Vec<String> = params
+            .into_iter()
+            .map(|(name, value)| {
+                let value = match name.as_str() {
+                    "chains" | "txTypes" | "tokens" => {
+                        let mut values: Vec<&str> = value.split(',').collect();
+                        values.sort_unstable();
+                        values.dedup();
+                        values.join(",")
+                    }
+                    _ => value,
+                };
+                format!("{}={}&", escape_query(&name), escape_query(&value))
+            })
+            .collect();
+        params.concat()
+    }
+
+    /// Returns a deterministic 64-bit hash of [`Filters::canonical_query`].
+    ///
+    /// The key is stable across runs and Rust versions, making it a compact key for external
+    /// persistent caches. Distinct queries may collide, so use the canonical query itself where
+    /// a collision would serve wrong results.
+    pub fn cache_key(&self) -> u64 {
+        // FNV-1a, which unlike `DefaultHasher` is specified and stable.
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        self.canonical_query()
+            .bytes()
+            .fold(OFFSET_BASIS, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+            })
+    }
+
+    /// Returns the query parameters sent to the feed endpoint for these filters, in order.
+    ///
+    /// This is the single source of the query string built by the feed methods, which makes it
+    /// useful to inspect the exact request when debugging.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cielo_rs_sdk::api::feed::Filters;
+    ///
+    /// let filters = Filters {
+    ///     limit: Some(10),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(filters.to_query_pairs(), [("limit".to_string(), "10".to_string())]);
+    /// ```
+    pub fn to_query_pairs(&self) -> Vec<(String, String)> {
+        let mut params = Vec::new();
+
+        if let Some(wallet) = &self.wallet {
+            params.push(("wallet".to_string(), wallet.clone()));
+        }
+        if let Some(limit) = self.limit {
+            params.push(("limit".to_string(), limit.to_string()));
+        }
+        if let Some(list_id) = self.list_id {
+            params.push(("list".to_string(), list_id.to_string()));
+        }
+        if let Some(chains) = &self.chains {
+            params.push(("chains".to_string(), chains.join(",")));
+        }
+        if let Some(tx_types) = &self.tx_types {
+            let tx_types_str: Vec<String> = tx_types.iter().map(|tx| tx.to_string()).collect();
+            params.push(("txTypes".to_string(), tx_types_str.join(",")));
+        }
+        if let Some(tokens) = &self.tokens {
+            params.push(("tokens".to_string(), tokens.join(",")));
+        }
+        if let Some(min_usd) = self.min_usd {
+            params.push(("minUSD".to_string(), min_usd.to_string()));
+        }
+        if let Some(new_trades) = self.new_trades {
+            params.push(("newTrades".to_string(), new_trades.to_string()));
+        }
+        if let Some(start_from) = &self.start_from {
+            params.push(("startFrom".to_string(), start_from.clone()));
+        }
+        if let Some(offset) = self.offset {
+            params.push(("offset".to_string(), offset.to_string()));
+        }
+        if let Some(from_timestamp) = self.from_timestamp {
+            params.push(("fromTimestamp".to_string(), from_timestamp.to_string()));
+        }
+        if let Some(to_timestamp) = self.to_timestamp {
+            params.push(("toTimestamp".to_string(), to_timestamp.to_string()));
+        }
+        if let Some(include_market_cap) = self.include_market_cap {
+            params.push((
+                "includeMarketCap".to_string(),
+                include_market_cap.to_string(),
+            ));
+        }
+        if let Some(sort) = self.sort {
+            params.push(("sort".to_string(), sort.to_string()));
+        }
+        if let Some(extra) = &self.extra {
+            let typed = params.len();
+            for (name, value) in extra {
+                if !params[..typed]
+                    .iter()
+                    .any(|(typed_name, _)| typed_name == name)
+                {
+                    params.push((name.clone(), value.clone()));
+                }
+            }
+        }
+
+        params
+    }
+
+    /// Checks that at most one of `start_from` and `offset` is set.
+    fn check_paging(&self) -> Result<(), crate::Error> {
+        if self.start_from.is_some() && self.offset.is_some() {
+            return Err(crate::Error::InvalidFilter(
+                "start_from and offset are mutually exclusive".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns filters for full pages of swaps worth at least $1,000, e.g. to follow smart money.
+    ///
+    /// Like every preset, the result can be refined with struct update syntax or the chaining
+    /// methods such as [`Filters::since`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use cielo_rs_sdk::api::feed::Filters;
+    ///
+    /// let filters = Filters {
+    ///     chains: Some(vec!["solana".to_string()]),
+    ///     ..Filters::preset_smart_swaps()
+    /// }
+    /// .since(Duration::from_secs(60 * 60));
+    /// ```
+    pub fn preset_smart_swaps() -> Self {
+        Self {
+            limit: Some(constants::MAX_PAGE_LIMIT),
+            tx_types: Some(vec![TxType::Swap]),
+            min_usd: Some(1_000),
+            ..Default::default()
+        }
+    }
+
+    /// Returns filters for full pages of transfers worth at least $10,000, e.g. to spot whale
+    /// movements.
+    pub fn preset_large_transfers() -> Self {
+        Self {
+            limit: Some(constants::MAX_PAGE_LIMIT),
+            tx_types: Some(vec![TxType::Transfer]),
+            min_usd: Some(10_000),
+            ..Default::default()
+        }
+    }
+
+    /// Returns filters for full pages of swaps of tokens the wallets traded for the first time.
+    pub fn preset_new_trades() -> Self {
+        Self {
+            limit: Some(constants::MAX_PAGE_LIMIT),
+            tx_types: Some(vec![TxType::Swap]),
+            new_trades: Some(true),
+            ..Default::default()
+        }
+    }
+
+    /// Returns filters for full pages of NFT trades and sweeps.
+    pub fn preset_nft_trades() -> Self {
+        Self {
+            limit: Some(constants::MAX_PAGE_LIMIT),
+            tx_types: Some(vec![TxType::NftTrade, TxType::NftSweep]),
+            ..Default::default()
+        }
+    }
+
+    /// Restricts the feed to transactions from `duration` ago onwards.
+    ///
+    /// `from_timestamp` is computed once, from the local system time at the time of the call, and
+    /// `to_timestamp` is left unchanged. The API compares timestamps against its own clock, so a
+    /// skewed local clock shifts the window accordingly; widen the duration by a margin when
+    /// missing items matters more than receiving duplicates.
+    ///
+    /// See [`Filters::since_with_clock`] to use another clock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use cielo_rs_sdk::api::feed::Filters;
+    ///
+    /// let filters = Filters::default().since(Duration::from_secs(15 * 60));
+    /// assert!(filters.from_timestamp.is_some());
+    /// ```
+    pub fn since(self, duration: Duration) -> Self {
+        self.since_with_clock(duration, &SystemClock)
+    }
+
+    /// Restricts the feed to transactions from `duration` ago onwards, using the given clock as
+    /// the current time.
+    ///
+    /// See [`Filters::since`].
+    pub fn since_with_clock(mut self, duration: Duration, clock: &dyn Clock) -> Self {
+        self.from_timestamp = Some(clock.now().saturating_sub(duration.as_secs()));
+        self
+    }
+
+    /// Restricts the feed to transactions within the last `duration`, up to the present.
+    ///
+    /// This is [`Filters::since`] with any `to_timestamp` cleared, so the window ends at the
+    /// newest transaction. The same clock-skew caveats apply.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use cielo_rs_sdk::api::feed::Filters;
+    ///
+    /// let filters = Filters::default().within_last(Duration::from_secs(60 * 60));
+    /// assert!(filters.to_timestamp.is_none());
+    /// ```
+    pub fn within_last(self, duration: Duration) -> Self {
+        self.within_last_with_clock(duration, &SystemClock)
+    }
+
+    /// Restricts the feed to transactions within the last `duration`, using the given clock as
+    /// the current time.
+    ///
+    /// See [`Filters::within_last`].
+    pub fn within_last_with_clock(mut self, duration: Duration, clock: &dyn Clock) -> Self {
+        self.to_timestamp = None;
+        self.since_with_clock(duration, clock)
+    }
+
+    /// Restricts the feed to new trades, i.e. the first purchases of a token by the tracked
+    /// wallets, when `first_buys_only` is true.
+    ///
+    /// This sets [`Filters::new_trades`] under a clearer name. On the returned items,
+    /// [`models::feed::Item::is_first_interaction`] tells first interactions apart client-side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cielo_rs_sdk::api::feed::Filters;
+    ///
+    /// let filters = Filters::default().first_buys_only(true);
+    /// assert_eq!(filters.new_trades, Some(true));
+    /// ```
+    pub fn first_buys_only(mut self, first_buys_only: bool) -> Self {
+        self.new_trades = Some(first_buys_only);
+        self
+    }
+
+    /// Restricts the feed to a wallet whose address was validated with [`Address::parse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cielo_rs_sdk::api::feed::{Address, Chain, Filters};
+    ///
+    /// let wallet = Address::parse(Chain::Ethereum, "0xd8da6bf26964af9d7eed9e03e53415d37aa96045")
+    ///     .unwrap();
+    /// let filters = Filters::default().wallet_address(&wallet);
+    /// assert_eq!(filters.wallet.as_deref(), Some(wallet.as_str()));
+    /// ```
+    pub fn wallet_address(mut self, address: &Address) -> Self {
+        self.wallet = Some(address.as_str().to_string());
+        self
+    }
+}
+
+/// A token to filter the feed by, identified either by its contract address or its symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenFilter {
+    /// A token contract address, validated against the filtered chains.
+    Address(String),
+    /// A token symbol, e.g. `USDC`.
+    Symbol(String),
+}
+
+impl TokenFilter {
+    /// Returns the raw value sent to the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            TokenFilter::Address(value) | TokenFilter::Symbol(value) => value,
+        }
+    }
+}
+
+impl Filters {
+    /// Sets the tokens to filter by, validating each of them.
+    ///
+    /// Addresses must be well-formed for at least one of the filtered `chains` (see
+    /// [`Chain::is_valid_address`]), so `chains` should be set first. When no chain is set, any
+    /// EVM, Solana or Tron address is accepted. Symbols must be non-empty and free of
+    /// whitespace and commas. The tokens are sent in the same `tokens` parameter as
+    /// [`Filters::tokens`], which can still be set directly for unvalidated values.
+    ///
+    /// # Errors
+    ///
+    /// This function returns [`crate::Error::InvalidFilter`] if a token is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cielo_rs_sdk::api::feed::{Filters, TokenFilter};
+    ///
+    /// let filters = Filters {
+    ///     chains: Some(vec!["ethereum".to_string()]),
+    ///     ..Default::default()
+    /// }
+    /// .token_filters(vec![
+    ///     TokenFilter::Address("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48".to_string()),
+    ///     TokenFilter::Symbol("PEPE".to_string()),
+    /// ])
+    /// .unwrap();
+    /// ```
+    pub fn token_filters(mut self, tokens: Vec<TokenFilter>) -> Result<Self, crate::Error> {
+        let chains: Vec<Chain> = match &self.chains {
+            Some(chains) => chains
+                .iter()
+                .map(|chain| {
+                    chain
+                        .parse()
+                        .unwrap_or_else(|never: Infallible| match never {})
+                })
+                .collect(),
+            None => vec![Chain::Ethereum, Chain::Solana, Chain::Tron],
+        };
+
+        for token in &tokens {
+            let is_valid = match token {
+                TokenFilter::Address(address) => {
+                    chains.iter().any(|chain| chain.is_valid_address(address))
+                }
+                TokenFilter::Symbol(symbol) => {
+                    !symbol.is_empty() && !symbol.contains(|c: char| c.is_whitespace() || c == ',')
+                }
+            };
+            if !is_valid {
+                return Err(crate::Error::InvalidFilter(format!(
+                    "malformed token {:?}",
+                    token
+                )));
+            }
+        }
+
+        self.tokens = Some(
+            tokens
+                .iter()
+                .map(|token| token.as_str().to_string())
+                .collect(),
+        );
+        Ok(self)
+    }
+}
+
+/// Client-side filters applied to fetched feed items, complementing the server-side [`Filters`].
+#[derive(Debug, Clone, Default)]
+pub struct FeedFilter {
+    /// Wallets to mute. Items whose wallet, `from` or `to` address is in the set are dropped.
+    /// EVM addresses are matched case-insensitively.
+    pub exclude_wallets: Option<HashSet<String>>,
+}
+
+impl FeedFilter {
+    /// Returns whether the item passes the filter.
+    pub fn matches(&self, item: &models::feed::Item) -> bool {
+        let Some(exclude_wallets) = &self.exclude_wallets else {
+            return true;
+        };
+
+        let is_excluded = |address: &str| {
+            exclude_wallets
+                .iter()
+                .any(|wallet| models::feed::addresses_eq(wallet, address))
+        };
+        !(is_excluded(item.wallet())
+            || is_excluded(item.from())
+            || item.to().is_some_and(is_excluded))
+    }
+
+    /// Drops the items that do not pass the filter, preserving the order of the others.
+    pub fn apply(&self, items: Vec<models::feed::Item>) -> Vec<models::feed::Item> {
+        items
+            .into_iter()
+            .filter(|item| self.matches(item))
+            .collect()
+    }
+}
+
+/// Sort orders for the feed, based on the transaction timestamp.
+#[derive(Display, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SortOrder {
+    /// Oldest transactions first, useful for backfills.
+    #[strum(serialize = "asc")]
+    #[serde(rename = "asc")]
+    Ascending,
+    /// Newest transactions first.
+    #[strum(serialize = "desc")]
+    #[serde(rename = "desc")]
+    Descending,
 }
 
 /// Transaction types for filtering the feed.
 ///
 /// This enum represents various types of transactions that can be used to filter the feed.
 /// Each variant corresponds to a specific type of transaction in the Cielo ecosystem.
-#[derive(Display, Debug, Clone)]
+///
+/// Transaction types display as, and parse from, their snake_case API names (e.g. `nft_trade`).
+/// Parsing an unknown name fails with a [`strum::ParseError`].
+#[derive(
+    Display, EnumString, EnumIter, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
 #[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
 pub enum TxType {
     /// Bridge transaction between different chains or networks
     Bridge,
@@ -85,6 +573,476 @@ pub enum TxType {
     Wrap,
 }
 
+/// Blockchain networks supported by the Cielo feed.
+///
+/// Chains not known to this SDK are represented by [`Chain::Other`], so parsing never fails.
+/// Chains can key maps and sets; parse chain names rather than constructing [`Chain::Other`]
+/// directly, so that a known chain always compares equal to its variant.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Chain {
+    /// Ethereum mainnet
+    Ethereum,
+    /// Solana
+    Solana,
+    /// Arbitrum One
+    Arbitrum,
+    /// Base
+    Base,
+    /// Optimism
+    Optimism,
+    /// Polygon PoS
+    Polygon,
+    /// BNB Smart Chain
+    Bsc,
+    /// Avalanche C-Chain
+    Avalanche,
+    /// Fantom Opera
+    Fantom,
+    /// Linea
+    Linea,
+    /// zkSync Era
+    Zksync,
+    /// Blast
+    Blast,
+    /// Scroll
+    Scroll,
+    /// Zora
+    Zora,
+    /// Tron
+    Tron,
+    /// Any other chain, holding the identifier used by the API.
+    Other(String),
+}
+
+/// Static information about a known [`Chain`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChainInfo {
+    /// The symbol of the chain's native currency, e.g. `ETH`.
+    pub native_symbol: &'static str,
+    /// Whether the chain is EVM-compatible, i.e. uses `0x`-prefixed hex addresses.
+    pub is_evm: bool,
+}
+
+impl Chain {
+    /// Returns static information about the chain, or `None` for [`Chain::Other`].
+    pub fn info(&self) -> Option<ChainInfo> {
+        let (native_symbol, is_evm) = match self {
+            Chain::Ethereum => ("ETH", true),
+            Chain::Solana => ("SOL", false),
+            Chain::Arbitrum => ("ETH", true),
+            Chain::Base => ("ETH", true),
+            Chain::Optimism => ("ETH", true),
+            Chain::Polygon => ("POL", true),
+            Chain::Bsc => ("BNB", true),
+            Chain::Avalanche => ("AVAX", true),
+            Chain::Fantom => ("FTM", true),
+            Chain::Linea => ("ETH", true),
+            Chain::Zksync => ("ETH", true),
+            Chain::Blast => ("ETH", true),
+            Chain::Scroll => ("ETH", true),
+            Chain::Zora => ("ETH", true),
+            Chain::Tron => ("TRX", false),
+            Chain::Other(_) => return None,
+        };
+        Some(ChainInfo {
+            native_symbol,
+            is_evm,
+        })
+    }
+
+    /// Returns whether the address is well-formed for the chain.
+    ///
+    /// EVM chains expect `0x` followed by 40 hexadecimal digits, Solana a base58 string of 32 to
+    /// 44 characters and Tron a base58 string of 34 characters starting with `T`. Addresses on
+    /// [`Chain::Other`] only need to be non-empty and free of whitespace and commas.
+    pub fn is_valid_address(&self, address: &str) -> bool {
+        let is_base58 = |s: &str| {
+            s.chars()
+                .all(|c| c.is_ascii_alphanumeric() && !matches!(c, '0' | 'O' | 'I' | 'l'))
+        };
+
+        match self {
+            Chain::Solana => (32..=44).contains(&address.len()) && is_base58(address),
+            Chain::Tron => address.len() == 34 && address.starts_with('T') && is_base58(address),
+            Chain::Other(_) => {
+                !address.is_empty() && !address.contains(|c: char| c.is_whitespace() || c == ',')
+            }
+            _ => address
+                .strip_prefix("0x")
+                .is_some_and(|hex| hex.len() == 40 && hex.chars().all(|c| c.is_ascii_hexdigit())),
+        }
+    }
+
+    /// Returns the identifier used by the Cielo API for the chain.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Chain::Ethereum => "ethereum",
+            Chain::Solana => "solana",
+            Chain::Arbitrum => "arbitrum",
+            Chain::Base => "base",
+            Chain::Optimism => "optimism",
+            Chain::Polygon => "polygon",
+            Chain::Bsc => "bsc",
+            Chain::Avalanche => "avalanche",
+            Chain::Fantom => "fantom",
+            Chain::Linea => "linea",
+            Chain::Zksync => "zksync",
+            Chain::Blast => "blast",
+            Chain::Scroll => "scroll",
+            Chain::Zora => "zora",
+            Chain::Tron => "tron",
+            Chain::Other(chain) => chain,
+        }
+    }
+}
+
+impl fmt::Display for Chain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Chain {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ethereum" => Chain::Ethereum,
+            "solana" => Chain::Solana,
+            "arbitrum" => Chain::Arbitrum,
+            "base" => Chain::Base,
+            "optimism" => Chain::Optimism,
+            "polygon" => Chain::Polygon,
+            "bsc" => Chain::Bsc,
+            "avalanche" => Chain::Avalanche,
+            "fantom" => Chain::Fantom,
+            "linea" => Chain::Linea,
+            "zksync" => Chain::Zksync,
+            "blast" => Chain::Blast,
+            "scroll" => Chain::Scroll,
+            "zora" => Chain::Zora,
+            "tron" => Chain::Tron,
+            other => Chain::Other(other.to_string()),
+        })
+    }
+}
+
+/// An address validated for the chain it belongs to.
+///
+/// Parsing catches malformed wallet and token addresses before they reach the API. The
+/// address is kept exactly as given, and can be used with [`Filters::wallet_address`] or, as a
+/// [`TokenFilter::Address`], with [`Filters::token_filters`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Address {
+    /// The chain the address was validated for.
+    chain: Chain,
+    /// The address as given.
+    address: String,
+}
+
+impl Address {
+    /// Parses an address, checking that it is well-formed for the chain.
+    ///
+    /// See [`Chain::is_valid_address`] for the accepted formats.
+    ///
+    /// # Errors
+    ///
+    /// This function returns [`crate::Error::InvalidFilter`] if the address is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cielo_rs_sdk::api::feed::{Address, Chain};
+    ///
+    /// assert!(Address::parse(Chain::Solana, "So11111111111111111111111111111111111111112").is_ok());
+    /// assert!(Address::parse(Chain::Ethereum, "0x1234").is_err());
+    /// ```
+    pub fn parse(chain: Chain, address: &str) -> Result<Self, crate::Error> {
+        if !chain.is_valid_address(address) {
+            return Err(crate::Error::InvalidFilter(format!(
+                "malformed {} address {:?}",
+                chain, address
+            )));
+        }
+        Ok(Self {
+            chain,
+            address: address.to_string(),
+        })
+    }
+
+    /// Returns the chain the address was validated for.
+    pub fn chain(&self) -> &Chain {
+        &self.chain
+    }
+
+    /// Returns the address as given.
+    pub fn as_str(&self) -> &str {
+        &self.address
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.address)
+    }
+}
+
+impl From<Address> for TokenFilter {
+    fn from(address: Address) -> Self {
+        TokenFilter::Address(address.address)
+    }
+}
+
+/// A bounded window of recently seen feed items, used to drop duplicates across fetches.
+///
+/// Items are identified by their `(tx_hash, index)` pair. Once the window holds `capacity`
+/// identities, the oldest one is evicted for every new one, keeping memory usage constant over
+/// long runs. The tradeoff is that an item re-appearing after its identity was evicted is
+/// treated as new again, so the capacity should comfortably exceed the number of items that can
+/// be re-served between fetches.
+#[derive(Debug, Clone)]
+pub struct DedupWindow {
+    /// The maximum number of identities held.
+    capacity: usize,
+    /// The identities currently held, for fast lookups.
+    seen: HashSet<(String, u32)>,
+    /// The identities currently held, oldest first, for eviction.
+    order: VecDeque<(String, u32)>,
+}
+
+impl DedupWindow {
+    /// The capacity used by [`DedupWindow::default`].
+    pub const DEFAULT_CAPACITY: usize = 10_000;
+
+    /// Creates an empty window holding at most `capacity` identities (at least one).
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            seen: HashSet::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Records the item and returns `true` if it was not already in the window.
+    pub fn insert(&mut self, item: &models::feed::Item) -> bool {
+        let identity = (item.tx_hash().to_string(), item.index());
+        if self.seen.contains(&identity) {
+            return false;
+        }
+
+        if self.order.len() == self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+        self.seen.insert(identity.clone());
+        self.order.push_back(identity);
+        true
+    }
+
+    /// Keeps only the items not already in the window, recording them.
+    pub fn retain_new(&mut self, items: Vec<models::feed::Item>) -> Vec<models::feed::Item> {
+        items.into_iter().filter(|item| self.insert(item)).collect()
+    }
+
+    /// Returns the number of identities currently held.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Returns `true` if the window holds no identities.
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Returns the maximum number of identities held.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl Default for DedupWindow {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+/// Percent-encodes the characters that delimit parameters in [`Filters::canonical_query`].
+fn escape_query(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace('&', "%26")
+        .replace('=', "%3D")
+}
+
+/// An in-memory cache of feed results, keyed on [`Filters::canonical_query`].
+#[derive(Debug)]
+pub(crate) struct FeedCache {
+    /// How long results are served from the cache.
+    ttl: Duration,
+    /// The cached results along with the time they were fetched.
+    entries: Mutex<HashMap<String, (Instant, Vec<models::feed::Item>)>>,
+}
+
+impl FeedCache {
+    /// Creates an empty cache serving results for `ttl`.
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the cached result for the key, if fetched within the TTL.
+    fn get(&self, key: &str) -> Option<Vec<models::feed::Item>> {
+        let entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        entries
+            .get(key)
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < self.ttl)
+            .map(|(_, items)| items.clone())
+    }
+
+    /// Caches a result for the key, dropping expired entries.
+    fn insert(&self, key: String, items: Vec<models::feed::Item>) {
+        let mut entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        entries.retain(|_, (fetched_at, _)| fetched_at.elapsed() < self.ttl);
+        entries.insert(key, (Instant::now(), items));
+    }
+}
+
+/// Options for [`CieloApi::watch_feed`].
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+    /// Whether the items of the first poll are emitted, instead of only being recorded as the
+    /// baseline that later polls are compared against.
+    pub emit_initial: bool,
+    /// The number of consecutive failed polls after which the stream ends. A value of 0 is
+    /// treated as 1.
+    pub max_consecutive_errors: u32,
+    /// A token ending the stream once cancelled, including while waiting for a poll.
+    #[cfg(feature = "cancellation")]
+    pub cancellation: Option<tokio_util::sync::CancellationToken>,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            emit_initial: false,
+            max_consecutive_errors: 3,
+            #[cfg(feature = "cancellation")]
+            cancellation: None,
+        }
+    }
+}
+
+/// Resolves once the watch is cancelled through [`WatchOptions::cancellation`].
+#[cfg(feature = "cancellation")]
+async fn watch_cancelled(options: &WatchOptions) {
+    match &options.cancellation {
+        Some(token) => token.cancelled().await,
+        None => future::pending().await,
+    }
+}
+
+/// Never resolves, as watches cannot be cancelled without the `cancellation` feature.
+#[cfg(not(feature = "cancellation"))]
+async fn watch_cancelled(_options: &WatchOptions) {
+    future::pending().await
+}
+
+/// Options for [`CieloApi::get_feed_all`].
+#[derive(Debug, Clone, Default)]
+pub struct FeedAllOptions {
+    /// The maximum number of items to return, if any. The items are truncated to exactly this
+    /// many when the cap is reached in the middle of a page, and no further page is requested.
+    pub max_items: Option<usize>,
+    /// A token stopping the fetch once cancelled, including while a page is being fetched.
+    #[cfg(feature = "cancellation")]
+    pub cancellation: Option<tokio_util::sync::CancellationToken>,
+}
+
+/// Resolves once the fetch is cancelled through [`FeedAllOptions::cancellation`].
+#[cfg(feature = "cancellation")]
+async fn feed_all_cancelled(options: &FeedAllOptions) {
+    match &options.cancellation {
+        Some(token) => token.cancelled().await,
+        None => future::pending().await,
+    }
+}
+
+/// Never resolves, as fetches cannot be cancelled without the `cancellation` feature.
+#[cfg(not(feature = "cancellation"))]
+async fn feed_all_cancelled(_options: &FeedAllOptions) {
+    future::pending().await
+}
+
+/// The state of a stream created by [`CieloApi::watch_feed`].
+struct WatchState {
+    /// The filters of every poll.
+    filters: Filters,
+    /// The options of the watch.
+    options: WatchOptions,
+    /// The items seen so far.
+    window: DedupWindow,
+    /// The new items not yet yielded, oldest first.
+    buffer: VecDeque<models::feed::Item>,
+    /// Whether no poll succeeded yet, making the next successful one the baseline.
+    baseline: bool,
+    /// Whether a poll was already issued, so that the next one waits for the poll interval.
+    polled: bool,
+    /// The number of consecutive failed polls.
+    errors: u32,
+}
+
+/// The state of a stream created by [`CieloApi::get_feed_stream`].
+struct FeedStreamState {
+    /// The filters of the next page to fetch.
+    filters: Filters,
+    /// The items fetched but not yet yielded.
+    buffer: VecDeque<models::feed::Item>,
+    /// Whether there are no more pages to fetch.
+    finished: bool,
+}
+
+/// Metadata describing how a feed request was served.
+#[derive(Debug, Clone)]
+pub struct FeedMeta {
+    /// Every attempt made for the request, in order. The last one produced the final response.
+    pub attempts: Vec<RetryAttempt>,
+    /// The paging information returned with the feed items.
+    pub paging: models::Paging,
+}
+
+/// A per-wallet summary of feed items.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WalletRollup {
+    /// The number of items belonging to the wallet.
+    pub count: usize,
+    /// The total USD value of the wallet's items. Items without a USD value are not counted.
+    pub total_usd: f64,
+    /// The UNIX timestamp of the wallet's most recent item.
+    pub last_seen: u64,
+}
+
+/// Feed items along with hints about why the result may be unexpectedly empty.
+#[derive(Debug, Clone)]
+pub struct FeedResult {
+    /// The fetched feed items.
+    pub items: Vec<models::feed::Item>,
+    /// Whether the result is empty while filtering by wallet or list.
+    ///
+    /// The API only returns transactions of wallets in the account's watchlist, so an empty
+    /// result for a wallet or list filter often means the wallet is not tracked rather than
+    /// inactive.
+    pub possibly_unwatched: bool,
+}
+
 impl CieloApi {
     /// Fetches the feed based on the provided filters.
     ///
@@ -96,7 +1054,8 @@ impl CieloApi {
     ///
     /// # Errors
     ///
-    /// This function returns a `crate::Error` if the request fails or the response status is not 200 OK.
+    /// This function returns [`crate::Error::Unauthorized`] if the API rejects the API key, or
+    /// another `crate::Error` if the request fails or the response status is not 200 OK.
     ///
     /// # Examples
     ///
@@ -119,6 +1078,7 @@ impl CieloApi {
     ///         from_timestamp: None,
     ///         to_timestamp: None,
     ///         include_market_cap: Some(true),
+    ///         ..Default::default()
     ///     };
     ///     let feed = cielo_api.get_feed(filters).await.unwrap();
     /// # }
@@ -127,59 +1087,1483 @@ impl CieloApi {
         &self,
         filters: Filters,
     ) -> Result<Vec<models::feed::Item>, crate::Error> {
-        let url = format!("{}feed", constants::URL);
+        let Some(cache) = &self.feed_cache else {
+            let response = self.get_feed_response(filters).await?;
+            return Ok(response.data.items);
+        };
 
-        let mut request = self.client.get(url);
+        let key = filters.canonical_query();
+        if let Some(items) = cache.get(&key) {
+            return Ok(items);
+        }
+        let response = self.get_feed_response(filters).await?;
+        cache.insert(key, response.data.items.clone());
+        Ok(response.data.items)
+    }
+
+    /// Fetches a single page of the feed, returning the full response envelope.
+    ///
+    /// Unlike [`CieloApi::get_feed`], which only returns the items, the envelope includes the
+    /// `status`, the `message` the API may attach as a soft warning, and the paging information
+    /// needed to fetch the next page. Results are never cached.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if the request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let response = cielo_api
+    ///     .get_feed_response(api::feed::Filters::default())
+    ///     .await
+    ///     .unwrap();
+    /// if let Some(message) = &response.message {
+    ///     println!("{}: {message}", response.status);
+    /// }
+    /// println!("More pages: {}", response.data.paging.has_next_page);
+    /// # }
+    /// ```
+    pub async fn get_feed_response(
+        &self,
+        filters: Filters,
+    ) -> Result<models::Response<models::feed::Item>, crate::Error> {
+        self.send_feed_request(self.feed_request(filters)?).await
+    }
+
+    /// Returns the timestamp of the newest item matching the filters, or `None` if there is none.
+    ///
+    /// Only a single item is fetched, making this a cheap health check for polling bots to
+    /// detect a stalled feed. The `limit` and `sort` filters are overridden, and the response
+    /// cache is bypassed so that a stalled feed is not masked by a cached result.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if the request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// if let Some(timestamp) = cielo_api
+    ///     .latest_timestamp(api::feed::Filters::default())
+    ///     .await
+    ///     .unwrap()
+    /// {
+    ///     println!("Latest item at {timestamp}");
+    /// }
+    /// # }
+    /// ```
+    pub async fn latest_timestamp(&self, filters: Filters) -> Result<Option<u64>, crate::Error> {
+        let filters = Filters {
+            limit: Some(1),
+            sort: Some(SortOrder::Descending),
+            ..filters
+        };
+
+        let response = self.get_feed_response(filters).await?;
+        Ok(response.data.items.first().map(|item| item.timestamp()))
+    }
+
+    /// Fetches the feed in a single attempt, without retrying transient failures.
+    ///
+    /// This suits latency-sensitive callers with a hard deadline, such as request handlers: a
+    /// failure is returned immediately instead of after the retries and their backoff. The
+    /// tradeoff is that transient errors (rate limits, 5xx responses, dropped connections) that
+    /// a retry would have absorbed are surfaced to the caller. The retry callback is not invoked.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if the request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let feed = cielo_api
+    ///     .get_feed_no_retry(api::feed::Filters::default())
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn get_feed_no_retry(
+        &self,
+        filters: Filters,
+    ) -> Result<Vec<models::feed::Item>, crate::Error> {
+        filters.check_paging()?;
+        let request = self
+            .no_retry_client
+            .get(self.feed_url())
+            .query(&filters.to_query_pairs());
+
+        let response = self.send_feed_request(request).await?;
+        Ok(response.data.items)
+    }
+
+    /// Fetches the feed as the raw response body, without deserializing it.
+    ///
+    /// This suits forwarding the API payload unchanged, avoiding a parse and reserialize round
+    /// trip. Retries, status checks and the maximum response size apply as for
+    /// [`CieloApi::get_feed`], but the body is not validated, even in strict mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if the request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let body = cielo_api
+    ///     .get_feed_bytes(api::feed::Filters::default())
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn get_feed_bytes(&self, filters: Filters) -> Result<bytes::Bytes, crate::Error> {
+        let response = self.feed_request(filters)?.send().await?;
+
+        // Check if the response status is not one of the accepted ones
+        if !self.accept_status.contains(&response.status().as_u16()) {
+            return Err(status_error(response, self.max_response_bytes).await);
+        }
+
+        read_bytes(response, self.max_response_bytes).await
+    }
+
+    /// Fetches a single page of the feed with the numeric fields of each item as exact decimals.
+    ///
+    /// Unlike the `f64` fields of [`models::feed::Item`], the decimals keep every digit written
+    /// in the response. The body is fetched as with [`CieloApi::get_feed_bytes`] and parsed with
+    /// [`models::feed::DecimalItem::from_feed_body`]. Results are never cached.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if the request fails, the response status is not
+    /// 200 OK, or the body is not a valid feed response.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let items = cielo_api
+    ///     .get_feed_decimal(api::feed::Filters::default())
+    ///     .await
+    ///     .unwrap();
+    /// for item in &items {
+    ///     println!("{}: {:?}", item.item().tx_hash(), item.usd_value());
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "decimal")]
+    pub async fn get_feed_decimal(
+        &self,
+        filters: Filters,
+    ) -> Result<Vec<models::feed::DecimalItem>, crate::Error> {
+        let body = self.get_feed_bytes(filters).await?;
+        Ok(models::feed::DecimalItem::from_feed_body(&body)?)
+    }
+
+    /// Checks that the API is reachable and accepts the API key.
+    ///
+    /// A single-item feed page is requested without retrying, so the check returns promptly.
+    /// This suits startup checks and monitoring probes.
+    ///
+    /// # Errors
+    ///
+    /// This function returns [`crate::Error::Unauthorized`] if the API rejects the API key with a
+    /// 401 or 403 status, [`crate::Error::StatusNot200`] for other statuses not accepted by
+    /// [`crate::api::CieloApiBuilder::accept_status`], and
+    /// another `crate::Error` if the API cannot be reached.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::CieloApi;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// cielo_api.health_check().await.unwrap();
+    /// # }
+    /// ```
+    pub async fn health_check(&self) -> Result<(), crate::Error> {
+        let filters = Filters {
+            limit: Some(1),
+            ..Default::default()
+        };
+        let response = self
+            .no_retry_client
+            .get(self.feed_url())
+            .query(&filters.to_query_pairs())
+            .send()
+            .await?;
+
+        if !self.accept_status.contains(&response.status().as_u16()) {
+            return Err(status_error(response, self.max_response_bytes).await);
+        }
+        Ok(())
+    }
+
+    /// Fetches the feed like [`CieloApi::get_feed`], flagging empty results that may stem from an
+    /// unwatched wallet.
+    ///
+    /// Filtering by wallet only works for wallets in the account's watchlist, and the API returns
+    /// an empty feed otherwise. This opt-in variant sets [`FeedResult::possibly_unwatched`] when
+    /// no items are returned for a `wallet` or `list_id` filter.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if the request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let result = cielo_api
+    ///     .get_feed_checked(api::feed::Filters {
+    ///         wallet: Some("your_wallet_address".to_string()),
+    ///         ..Default::default()
+    ///     })
+    ///     .await
+    ///     .unwrap();
+    /// if result.possibly_unwatched {
+    ///     eprintln!("No items: is the wallet in your watchlist?");
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_feed_checked(&self, filters: Filters) -> Result<FeedResult, crate::Error> {
+        let filters_wallet = filters.wallet.is_some() || filters.list_id.is_some();
+        let items = self.get_feed(filters).await?;
+
+        Ok(FeedResult {
+            possibly_unwatched: filters_wallet && items.is_empty(),
+            items,
+        })
+    }
+
+    /// Streams feed items across all pages, fetching the next page as the stream is consumed.
+    ///
+    /// Pages are followed using `paging.next_object` until the API reports no further page.
+    /// When a page fetch fails with a transient error (see [`crate::Error::is_transient`]), it is
+    /// retried up to `page_retries` times from the same cursor before the error is yielded and
+    /// the stream ends, so a transient failure does not leave a gap in the ingested data. Other
+    /// errors, such as a rejected API key, are yielded right away.
+    ///
+    /// These page retries are separate from the HTTP-level retries of the client: each page
+    /// attempt already goes through the retry middleware with its backoff, so a page is only
+    /// retried here once the middleware gave up. Page retries back off exponentially between
+    /// the client's minimum and maximum retry intervals.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    /// * `page_retries` - How many times a failed page is retried before giving up.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # use futures::StreamExt;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let stream = cielo_api.get_feed_stream(api::feed::Filters::default(), 3);
+    /// futures::pin_mut!(stream);
+    /// while let Some(item) = stream.next().await {
+    ///     println!("{:?}", item.unwrap());
+    /// }
+    /// # }
+    /// ```
+    pub fn get_feed_stream(
+        &self,
+        filters: Filters,
+        page_retries: u32,
+    ) -> impl Stream<Item = Result<models::feed::Item, crate::Error>> + '_ {
+        let state = FeedStreamState {
+            filters,
+            buffer: VecDeque::new(),
+            finished: false,
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(item) = state.buffer.pop_front() {
+                    return Some((Ok(item), state));
+                }
+                if state.finished {
+                    return None;
+                }
+
+                let mut retries = 0;
+                let page = loop {
+                    match self.get_feed_response(state.filters.clone()).await {
+                        Ok(page) => break page,
+                        Err(error) if error.is_transient() && retries < page_retries => {
+                            let (min_interval, max_interval) = self.page_retry_intervals;
+                            let delay = min_interval
+                                .saturating_mul(2u32.saturating_pow(retries))
+                                .min(max_interval);
+                            Delay::new(delay).await;
+                            retries += 1;
+                        }
+                        Err(error) => {
+                            state.finished = true;
+                            return Some((Err(error), state));
+                        }
+                    }
+                };
+
+                let paging = page.data.paging;
+                state.buffer.extend(page.data.items);
+                match paging.next_object {
+                    Some(next_object) if paging.has_next_page => {
+                        state.filters.start_from = Some(next_object);
+                        state.filters.offset = None;
+                    }
+                    _ => state.finished = true,
+                }
+            }
+        })
+    }
+
+    /// Polls the feed on an interval and streams only the items that were not seen before.
+    ///
+    /// Items are identified by their `(tx_hash, index)` pair and tracked in a [`DedupWindow`] of
+    /// the default capacity. New items of each poll are yielded in timestamp order, oldest first.
+    /// Unless [`WatchOptions::emit_initial`] is set, the items of the first successful poll only
+    /// form the baseline and are not yielded.
+    ///
+    /// Every failed poll yields its error, and polling resumes after the interval. Once
+    /// [`WatchOptions::max_consecutive_errors`] polls failed in a row, the stream ends. With the
+    /// `cancellation` feature, it also ends as soon as its cancellation token is cancelled. It
+    /// never ends otherwise.
+    ///
+    /// Polls bypass the response cache enabled by [`crate::api::CieloApiBuilder::cache_ttl`],
+    /// which would otherwise hide new items whenever its TTL exceeds the poll interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    /// * `poll_interval` - The time to wait between polls.
+    /// * `options` - The baseline and error handling options.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # use futures::StreamExt;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let stream = cielo_api.watch_feed(
+    ///     api::feed::Filters::default(),
+    ///     Duration::from_secs(10),
+    ///     api::feed::WatchOptions::default(),
+    /// );
+    /// futures::pin_mut!(stream);
+    /// while let Some(item) = stream.next().await {
+    ///     println!("{:?}", item);
+    /// }
+    /// # }
+    /// ```
+    pub fn watch_feed(
+        &self,
+        filters: Filters,
+        poll_interval: Duration,
+        options: WatchOptions,
+    ) -> impl Stream<Item = Result<models::feed::Item, crate::Error>> + '_ {
+        let state = WatchState {
+            filters,
+            options,
+            window: DedupWindow::default(),
+            buffer: VecDeque::new(),
+            baseline: true,
+            polled: false,
+            errors: 0,
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(item) = state.buffer.pop_front() {
+                    return Some((Ok(item), state));
+                }
+                if state.errors >= state.options.max_consecutive_errors.max(1) {
+                    return None;
+                }
+
+                let poll = async {
+                    if state.polled {
+                        Delay::new(poll_interval).await;
+                    }
+                    self.get_feed_response(state.filters.clone())
+                        .await
+                        .map(|response| response.data.items)
+                };
+                let cancelled = watch_cancelled(&state.options);
+                let result =
+                    match future::select(std::pin::pin!(poll), std::pin::pin!(cancelled)).await {
+                        Either::Left((result, _)) => result,
+                        Either::Right(_) => return None,
+                    };
+                state.polled = true;
+
+                match result {
+                    Ok(items) => {
+                        state.errors = 0;
+                        let mut items = state.window.retain_new(items);
+                        if state.baseline && !state.options.emit_initial {
+                            items.clear();
+                        }
+                        state.baseline = false;
+
+                        items.sort_by_key(|item| item.timestamp());
+                        state.buffer.extend(items);
+                    }
+                    Err(error) => {
+                        state.errors += 1;
+                        return Some((Err(error), state));
+                    }
+                }
+            }
+        })
+    }
+
+    /// Streams the feed into a sink in batches, flushing on size or time, whichever comes first.
+    ///
+    /// Items from [`CieloApi::get_feed_stream`] are accumulated and passed to `sink` as soon as
+    /// `batch_size` items are pending, or once `max_interval` has elapsed since the last flush
+    /// with at least one item pending, which bounds latency while pages are slow to arrive. Any
+    /// pending items are flushed when the feed ends or fails. This suits ingestion pipelines such
+    /// as database writers.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    /// * `batch_size` - The maximum number of items per batch. A value of 0 is treated as 1.
+    /// * `max_interval` - The maximum time items may wait before being flushed.
+    /// * `sink` - The callback receiving each batch.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if a request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// cielo_api
+    ///     .feed_to_batched_sink(
+    ///         api::feed::Filters::default(),
+    ///         50,
+    ///         Duration::from_secs(5),
+    ///         |batch| println!("Writing {} items", batch.len()),
+    ///     )
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn feed_to_batched_sink(
+        &self,
+        filters: Filters,
+        batch_size: usize,
+        max_interval: Duration,
+        mut sink: impl FnMut(Vec<models::feed::Item>),
+    ) -> Result<(), crate::Error> {
+        let batch_size = batch_size.max(1);
+        let mut stream = std::pin::pin!(self.get_feed_stream(filters, 0));
+        let mut batch = Vec::with_capacity(batch_size);
+        let mut deadline = Delay::new(max_interval);
+
+        loop {
+            // The stream keeps an in-flight page request across polls, so it is safe to drop
+            // `next` when the deadline fires first.
+            match future::select(stream.next(), &mut deadline).await {
+                Either::Left((Some(Ok(item)), _)) => {
+                    batch.push(item);
+                    if batch.len() >= batch_size {
+                        sink(std::mem::replace(
+                            &mut batch,
+                            Vec::with_capacity(batch_size),
+                        ));
+                        deadline.reset(max_interval);
+                    }
+                }
+                Either::Left((result, _)) => {
+                    if !batch.is_empty() {
+                        sink(batch);
+                    }
+                    return result.transpose().map(|_| ());
+                }
+                Either::Right(((), _)) => {
+                    if !batch.is_empty() {
+                        sink(std::mem::replace(
+                            &mut batch,
+                            Vec::with_capacity(batch_size),
+                        ));
+                    }
+                    deadline.reset(max_interval);
+                }
+            }
+        }
+    }
+
+    /// Fetches the feed based on the provided filters, blocking the current thread.
+    ///
+    /// This is the synchronous counterpart of [`CieloApi::get_feed`] for callers without an
+    /// async runtime, such as CLI tools and scripts. It drives the same request, including
+    /// retries, on a dedicated single-threaded runtime owned by the client.
+    ///
+    /// <div class="warning">
+    /// This method must not be called from within an async context, as it would panic when
+    /// blocking. Likewise, a client that made blocking calls must not be dropped from within an
+    /// async context, and should not be shared with async code.
+    /// </div>
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if the runtime cannot be created, the request fails
+    /// or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let feed = cielo_api
+    ///     .blocking_get_feed(api::feed::Filters::default())
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "blocking")]
+    pub fn blocking_get_feed(
+        &self,
+        filters: Filters,
+    ) -> Result<Vec<models::feed::Item>, crate::Error> {
+        self.blocking_runtime()?.block_on(self.get_feed(filters))
+    }
+
+    /// Fetches the feed along with metadata describing how the request was served.
+    ///
+    /// In addition to the feed items, this returns a [`FeedMeta`] containing every attempt made
+    /// for the request (including retries) and the paging information of the response. This is
+    /// useful for debugging flaky requests, e.g. to see whether a success came on the first try.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if the request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let (items, meta) = cielo_api
+    ///     .get_feed_with_meta(api::feed::Filters::default())
+    ///     .await
+    ///     .unwrap();
+    /// println!("{} items after {} attempt(s)", items.len(), meta.attempts.len());
+    /// # }
+    /// ```
+    pub async fn get_feed_with_meta(
+        &self,
+        filters: Filters,
+    ) -> Result<(Vec<models::feed::Item>, FeedMeta), crate::Error> {
+        let log = AttemptLog::new();
+        let request = self.feed_request(filters)?.with_extension(log.clone());
+
+        let response = self.send_feed_request(request).await?;
+        let meta = FeedMeta {
+            attempts: log.attempts(),
+            paging: response.data.paging,
+        };
+        Ok((response.data.items, meta))
+    }
+
+    /// Fetches the feed along with a per-wallet rollup of the fetched items.
+    ///
+    /// The rollups are computed while iterating the items, sparing consumers such as multi-wallet
+    /// dashboards a second pass. They are keyed by the wallet address as returned by the API.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if the request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let (_, rollups) = cielo_api
+    ///     .get_feed_with_wallet_rollups(api::feed::Filters::default())
+    ///     .await
+    ///     .unwrap();
+    /// for (wallet, rollup) in rollups {
+    ///     println!("{wallet}: {} items, ${:.2}", rollup.count, rollup.total_usd);
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_feed_with_wallet_rollups(
+        &self,
+        filters: Filters,
+    ) -> Result<(Vec<models::feed::Item>, HashMap<String, WalletRollup>), crate::Error> {
+        let items = self.get_feed(filters).await?;
+
+        let mut rollups: HashMap<String, WalletRollup> = HashMap::new();
+        for item in &items {
+            let rollup = rollups.entry(item.wallet().to_string()).or_default();
+            rollup.count += 1;
+            rollup.total_usd += item.usd_value().unwrap_or_default();
+            rollup.last_seen = rollup.last_seen.max(item.timestamp());
+        }
+
+        Ok((items, rollups))
+    }
+
+    /// Fetches new trades, i.e. swaps that are a wallet's first purchase of a token.
+    ///
+    /// This is a thin wrapper over [`CieloApi::get_feed`] setting `new_trades` and restricting the
+    /// transaction types to swaps, which is the common setup for "first buy" alerting.
+    ///
+    /// # Arguments
+    ///
+    /// * `wallet` - An optional wallet address to restrict the feed to.
+    /// * `chains` - An optional list of chains to restrict the feed to.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if the request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api::feed::Chain};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let new_trades = cielo_api
+    ///     .get_new_trades(None, Some(vec![Chain::Solana]))
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn get_new_trades(
+        &self,
+        wallet: Option<&str>,
+        chains: Option<Vec<Chain>>,
+    ) -> Result<Vec<models::feed::Item>, crate::Error> {
+        let filters = Filters {
+            wallet: wallet.map(str::to_string),
+            chains: chains.map(|chains| chains.iter().map(Chain::to_string).collect()),
+            tx_types: Some(vec![TxType::Swap]),
+            new_trades: Some(true),
+            ..Default::default()
+        };
+        self.get_feed(filters).await
+    }
+
+    /// Fetches the activity of the wallets in a list, without any other filter.
+    ///
+    /// # Arguments
+    ///
+    /// * `list_id` - The ID of the list.
+    /// * `limit` - The maximum number of transactions to return, or the API default if `None`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if the request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::CieloApi;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let items = cielo_api.get_list_feed(42, Some(20)).await.unwrap();
+    /// # }
+    /// ```
+    pub async fn get_list_feed(
+        &self,
+        list_id: u64,
+        limit: Option<u32>,
+    ) -> Result<Vec<models::feed::Item>, crate::Error> {
+        let filters = Filters {
+            list_id: Some(list_id),
+            limit,
+            ..Default::default()
+        };
+        self.get_feed(filters).await
+    }
+
+    /// Fetches the activity involving a token across the wallets you track, e.g. to see who is
+    /// trading it.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The address or symbol of the token.
+    /// * `chains` - An optional list of chains to restrict the feed to.
+    /// * `limit` - The maximum number of transactions to return, or the API default if `None`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if the request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api::feed::Chain};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let items = cielo_api
+    ///     .get_token_activity("BONK", Some(vec![Chain::Solana]), Some(50))
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn get_token_activity(
+        &self,
+        token: &str,
+        chains: Option<Vec<Chain>>,
+        limit: Option<u32>,
+    ) -> Result<Vec<models::feed::Item>, crate::Error> {
+        let filters = Filters {
+            tokens: Some(vec![token.to_string()]),
+            chains: chains.map(|chains| chains.iter().map(Chain::to_string).collect()),
+            limit,
+            ..Default::default()
+        };
+        self.get_feed(filters).await
+    }
+
+    /// Fetches swaps matching the filters.
+    ///
+    /// The `tx_types` filter is overridden with [`TxType::Swap`] and only
+    /// [`models::feed::Item::Swap`] items are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if the request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let items = cielo_api
+    ///     .get_swaps(api::feed::Filters::default())
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn get_swaps(
+        &self,
+        filters: Filters,
+    ) -> Result<Vec<models::feed::Swap>, crate::Error> {
+        self.get_typed(filters, TxType::Swap, |item| match item {
+            models::feed::Item::Swap(item) => Some(item),
+            _ => None,
+        })
+        .await
+    }
 
-        // Apply filters to the request
-        if let Some(wallet) = filters.wallet {
-            request = request.query(&[("wallet", wallet)]);
-        }
-        if let Some(limit) = filters.limit {
-            request = request.query(&[("limit", limit.to_string())]);
-        }
-        if let Some(list_id) = filters.list_id {
-            request = request.query(&[("list", list_id.to_string())]);
-        }
-        if let Some(chains) = filters.chains {
-            request = request.query(&[("chains", chains.join(","))]);
-        }
-        if let Some(tx_types) = filters.tx_types {
-            let tx_types_str: Vec<String> = tx_types.iter().map(|tx| tx.to_string()).collect();
-            request = request.query(&[("txTypes", tx_types_str.join(","))]);
-        }
-        if let Some(tokens) = filters.tokens {
-            request = request.query(&[("tokens", tokens.join(","))]);
-        }
-        if let Some(min_usd) = filters.min_usd {
-            request = request.query(&[("minUSD", min_usd.to_string())]);
-        }
-        if let Some(new_trades) = filters.new_trades {
-            request = request.query(&[("newTrades", new_trades.to_string())]);
+    /// Fetches transfers matching the filters.
+    ///
+    /// The `tx_types` filter is overridden with [`TxType::Transfer`] and only
+    /// [`models::feed::Item::Transfer`] items are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if the request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let items = cielo_api
+    ///     .get_transfers(api::feed::Filters::default())
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn get_transfers(
+        &self,
+        filters: Filters,
+    ) -> Result<Vec<models::feed::Transfer>, crate::Error> {
+        self.get_typed(filters, TxType::Transfer, |item| match item {
+            models::feed::Item::Transfer(item) => Some(item),
+            _ => None,
+        })
+        .await
+    }
+
+    /// Fetches NFT trades matching the filters.
+    ///
+    /// The `tx_types` filter is overridden with [`TxType::NftTrade`] and only
+    /// [`models::feed::Item::NftTrade`] items are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if the request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let items = cielo_api
+    ///     .get_nft_trades(api::feed::Filters::default())
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn get_nft_trades(
+        &self,
+        filters: Filters,
+    ) -> Result<Vec<models::feed::NftTrade>, crate::Error> {
+        self.get_typed(filters, TxType::NftTrade, |item| match item {
+            models::feed::Item::NftTrade(item) => Some(item),
+            _ => None,
+        })
+        .await
+    }
+
+    /// Fetches liquidity pool transactions matching the filters.
+    ///
+    /// The `tx_types` filter is overridden with [`TxType::Lp`] and only
+    /// [`models::feed::Item::Lp`] items are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if the request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let items = cielo_api
+    ///     .get_lp(api::feed::Filters::default())
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn get_lp(&self, filters: Filters) -> Result<Vec<models::feed::Lp>, crate::Error> {
+        self.get_typed(filters, TxType::Lp, |item| match item {
+            models::feed::Item::Lp(item) => Some(item),
+            _ => None,
+        })
+        .await
+    }
+
+    /// Fetches the feed restricted to a single transaction type and extracts the typed items.
+    async fn get_typed<T>(
+        &self,
+        filters: Filters,
+        tx_type: TxType,
+        extract: fn(models::feed::Item) -> Option<T>,
+    ) -> Result<Vec<T>, crate::Error> {
+        let filters = Filters {
+            tx_types: Some(vec![tx_type]),
+            ..filters
+        };
+        let items = self.get_feed(filters).await?;
+        Ok(items.into_iter().filter_map(extract).collect())
+    }
+
+    /// Fetches a single transaction from the feed by its hash.
+    ///
+    /// The feed endpoint does not support filtering by transaction hash, so this scans the most
+    /// recent pages of the feed (up to 10 pages of 100 items) and returns the first item with a
+    /// matching hash. Transactions older than that window are not found. If a transaction emitted
+    /// several items, only the first one is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_hash` - The hash of the transaction to look up.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if a request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::CieloApi;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// if let Some(item) = cielo_api.get_tx("0x...").await.unwrap() {
+    ///     println!("{:?}", item);
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_tx(&self, tx_hash: &str) -> Result<Option<models::feed::Item>, crate::Error> {
+        let mut filters = Filters {
+            limit: Some(constants::MAX_PAGE_LIMIT),
+            ..Default::default()
+        };
+
+        for _ in 0..constants::TX_LOOKUP_MAX_PAGES {
+            let page = self.get_feed_response(filters.clone()).await?;
+            let paging = page.data.paging;
+
+            if let Some(item) = page
+                .data
+                .items
+                .into_iter()
+                .find(|item| item.tx_hash().eq_ignore_ascii_case(tx_hash))
+            {
+                return Ok(Some(item));
+            }
+
+            match paging.next_object {
+                Some(next_object) if paging.has_next_page => {
+                    filters.start_from = Some(next_object);
+                    filters.offset = None;
+                }
+                _ => break,
+            }
         }
-        if let Some(start_from) = filters.start_from {
-            request = request.query(&[("startFrom", start_from)]);
+
+        Ok(None)
+    }
+
+    /// Fetches every item since a timestamp, paging until an older item is encountered.
+    ///
+    /// Pages are requested newest first, overriding any `sort` filter, as the boundary relies on
+    /// that order. As soon as an item older than `floor_timestamp` is encountered, it and every
+    /// item after it are dropped and paging stops. Unlike the server-side `from_timestamp`
+    /// filter, the boundary is applied to exactly the items returned, which is useful when
+    /// merging backfills. Paging also stops after `max_pages` pages or at the end of the feed.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    /// * `floor_timestamp` - The UNIX timestamp of the oldest item to keep.
+    /// * `max_pages` - The maximum number of pages to fetch.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if a request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let items = cielo_api
+    ///     .get_feed_until_timestamp(api::feed::Filters::default(), 1_726_000_000, 20)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn get_feed_until_timestamp(
+        &self,
+        mut filters: Filters,
+        floor_timestamp: u64,
+        max_pages: usize,
+    ) -> Result<Vec<models::feed::Item>, crate::Error> {
+        filters.sort = Some(SortOrder::Descending);
+        let mut items = Vec::new();
+
+        for _ in 0..max_pages {
+            let page = self.get_feed_response(filters.clone()).await?;
+            let paging = page.data.paging;
+
+            for item in page.data.items {
+                if item.timestamp() < floor_timestamp {
+                    return Ok(items);
+                }
+                items.push(item);
+            }
+
+            match paging.next_object {
+                Some(next_object) if paging.has_next_page => {
+                    filters.start_from = Some(next_object);
+                    filters.offset = None;
+                }
+                _ => break,
+            }
         }
-        if let Some(from_timestamp) = filters.from_timestamp {
-            request = request.query(&[("fromTimestamp", from_timestamp.to_string())]);
+
+        Ok(items)
+    }
+
+    /// Backfills the feed from the newest item back to a target timestamp.
+    ///
+    /// Pages are requested newest first, overriding any `sort` filter, and followed with
+    /// `next_object` until an item older than `until_timestamp` is encountered; that item and
+    /// every item after it are dropped. Paging also stops at the end of the feed or after
+    /// [`BACKFILL_MAX_PAGES`] pages. This is [`CieloApi::get_feed_until_timestamp`] with that
+    /// safety cap.
+    ///
+    /// The feed is scoped to the account of the API key: it only covers the wallets tracked by
+    /// that account, so a backfill cannot reach activity of other wallets.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    /// * `until_timestamp` - The UNIX timestamp of the oldest item to keep.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if a request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let items = cielo_api
+    ///     .backfill_until(api::feed::Filters::default(), 1_726_000_000)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn backfill_until(
+        &self,
+        filters: Filters,
+        until_timestamp: u64,
+    ) -> Result<Vec<models::feed::Item>, crate::Error> {
+        self.get_feed_until_timestamp(filters, until_timestamp, BACKFILL_MAX_PAGES)
+            .await
+    }
+
+    /// Fetches up to `pages` pages of the feed and merges them, dropping duplicate items.
+    ///
+    /// When [`Filters::offset`] is set, pages are addressed by offset: page `i` starts at
+    /// `offset + i * limit`, with `limit` defaulting to the maximum page size, and up to
+    /// `concurrency` pages are fetched at once. Otherwise, paging follows the cursor returned with
+    /// each page, so pages cannot be requested ahead of time and are fetched serially, whatever
+    /// the value of `concurrency`. Either way, fetching stops at the first page reporting no
+    /// further pages.
+    ///
+    /// Items keep the order of the pages they belong to. Only the first occurrence of each
+    /// `(tx_hash, index)` pair is kept, as items may shift between pages while new transactions
+    /// arrive.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    /// * `pages` - The maximum number of pages to fetch.
+    /// * `concurrency` - The maximum number of pages to fetch at once.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if a request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let items = cielo_api
+    ///     .get_feed_parallel(api::feed::Filters::default(), 5, 2)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn get_feed_parallel(
+        &self,
+        filters: Filters,
+        pages: usize,
+        concurrency: usize,
+    ) -> Result<Vec<models::feed::Item>, crate::Error> {
+        let Some(offset) = filters.offset else {
+            // Cursor-based paging leaves no room for concurrent requests.
+            return self
+                .collect_pages(filters, pages, None, future::pending())
+                .await;
+        };
+
+        let limit = filters.limit.unwrap_or(constants::MAX_PAGE_LIMIT);
+        let mut responses = stream::iter(0..pages as u64)
+            .map(|page| {
+                self.get_feed_response(Filters {
+                    limit: Some(limit),
+                    offset: Some(offset + page * u64::from(limit)),
+                    ..filters.clone()
+                })
+            })
+            .buffered(concurrency.max(1));
+
+        let mut seen = HashSet::new();
+        let mut items = Vec::new();
+        while let Some(response) = responses.next().await {
+            let response = response?;
+            items.extend(
+                response
+                    .data
+                    .items
+                    .into_iter()
+                    .filter(|item| seen.insert((item.tx_hash().to_string(), item.index()))),
+            );
+            if !response.data.paging.has_next_page {
+                break;
+            }
         }
-        if let Some(to_timestamp) = filters.to_timestamp {
-            request = request.query(&[("toTimestamp", to_timestamp.to_string())]);
+
+        Ok(items)
+    }
+
+    /// Fetches up to `max_pages` pages of the feed like [`CieloApi::get_feed_parallel`], stopping
+    /// early as configured by `options`.
+    ///
+    /// Without options, only `max_pages` bounds the fetch. With [`FeedAllOptions::max_items`],
+    /// paging stops once that many items have been gathered. With
+    /// [`FeedAllOptions::cancellation`] (`cancellation` feature), cancellation is checked before
+    /// each page and while a page is being fetched, so a long-running fetch returns promptly on
+    /// shutdown with the items gathered so far, dropping an in-flight page.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    /// * `max_pages` - The maximum number of pages to fetch.
+    /// * `options` - The options stopping the fetch early.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `crate::Error` if a request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let options = api::feed::FeedAllOptions {
+    ///     max_items: Some(250),
+    ///     ..Default::default()
+    /// };
+    /// let items = cielo_api
+    ///     .get_feed_all(api::feed::Filters::default(), 10, options)
+    ///     .await
+    ///     .unwrap();
+    /// assert!(items.len() <= 250);
+    /// # }
+    /// ```
+    pub async fn get_feed_all(
+        &self,
+        filters: Filters,
+        max_pages: usize,
+        options: FeedAllOptions,
+    ) -> Result<Vec<models::feed::Item>, crate::Error> {
+        self.collect_pages(
+            filters,
+            max_pages,
+            options.max_items,
+            feed_all_cancelled(&options),
+        )
+        .await
+    }
+
+    /// Fetches up to `pages` pages, dropping duplicate items, until `max_items` items are
+    /// gathered or `cancelled` resolves.
+    async fn collect_pages(
+        &self,
+        mut filters: Filters,
+        pages: usize,
+        max_items: Option<usize>,
+        cancelled: impl Future<Output = ()>,
+    ) -> Result<Vec<models::feed::Item>, crate::Error> {
+        let mut cancelled = std::pin::pin!(cancelled);
+        let mut seen = HashSet::new();
+        let mut items = Vec::new();
+
+        for _ in 0..pages {
+            let fetch = std::pin::pin!(self.get_feed_response(filters.clone()));
+            let page = match future::select(fetch, cancelled.as_mut()).await {
+                Either::Left((page, _)) => page?,
+                Either::Right(_) => break,
+            };
+            let paging = page.data.paging;
+
+            items.extend(
+                page.data
+                    .items
+                    .into_iter()
+                    .filter(|item| seen.insert((item.tx_hash().to_string(), item.index()))),
+            );
+            if let Some(max_items) = max_items {
+                if items.len() >= max_items {
+                    items.truncate(max_items);
+                    break;
+                }
+            }
+
+            match paging.next_object {
+                Some(next_object) if paging.has_next_page => {
+                    filters.start_from = Some(next_object);
+                    filters.offset = None;
+                }
+                _ => break,
+            }
         }
 
-        let response = request.send().await?;
+        Ok(items)
+    }
+
+    /// Fetches the feed with a per-request timeout instead of the client-wide one.
+    ///
+    /// The timeout applies to each attempt, so retries may extend the total time spent.
+    /// Combine it with a low retry count on the client for a tight overall deadline.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - A Filters struct containing various filter options.
+    /// * `timeout` - The maximum time an attempt may take before being aborted.
+    ///
+    /// # Errors
+    ///
+    /// This function returns [`crate::Error::Timeout`] if the deadline elapses, or another
+    /// `crate::Error` if the request fails or the response status is not 200 OK.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use cielo_rs_sdk::{CieloApi, api};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let cielo_api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// let feed = cielo_api
+    ///     .get_feed_with_timeout(api::feed::Filters::default(), Duration::from_secs(2))
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn get_feed_with_timeout(
+        &self,
+        filters: Filters,
+        timeout: Duration,
+    ) -> Result<Vec<models::feed::Item>, crate::Error> {
+        let request = self.feed_request(filters)?.timeout(timeout);
+        let response = self.send_feed_request(request).await?;
+        Ok(response.data.items)
+    }
+
+    /// Builds a feed request with the provided filters applied as query parameters.
+    fn feed_request(
+        &self,
+        filters: Filters,
+    ) -> Result<reqwest_middleware::RequestBuilder, crate::Error> {
+        filters.check_paging()?;
+        Ok(self
+            .client
+            .get(self.feed_url())
+            .query(&filters.to_query_pairs()))
+    }
+
+    /// Returns the URL of the feed endpoint.
+    fn feed_url(&self) -> String {
+        format!("{}feed", self.base_url)
+    }
+
+    /// Sends a feed request and parses the response envelope.
+    ///
+    /// The request runs inside a `get_feed` span recording the redacted URL, the HTTP status,
+    /// the elapsed time and the number of items parsed.
+    #[cfg(feature = "tracing")]
+    async fn send_feed_request(
+        &self,
+        request: reqwest_middleware::RequestBuilder,
+    ) -> Result<models::Response<models::feed::Item>, crate::Error> {
+        use tracing::{field::Empty, Instrument};
+
+        let span = tracing::info_span!(
+            "get_feed",
+            url = %request_url(&request),
+            status = Empty,
+            elapsed_ms = Empty,
+            items = Empty
+        );
+        execute_feed_request(
+            request,
+            self.max_response_bytes,
+            self.strict_responses,
+            self.check_status,
+            &self.accept_status,
+        )
+        .instrument(span)
+        .await
+    }
+
+    /// Sends a feed request and parses the response envelope.
+    #[cfg(not(feature = "tracing"))]
+    async fn send_feed_request(
+        &self,
+        request: reqwest_middleware::RequestBuilder,
+    ) -> Result<models::Response<models::feed::Item>, crate::Error> {
+        execute_feed_request(
+            request,
+            self.max_response_bytes,
+            self.strict_responses,
+            self.check_status,
+            &self.accept_status,
+        )
+        .await
+    }
+}
+
+/// Sends a feed request and parses the response envelope, recording tracing fields if enabled.
+async fn execute_feed_request(
+    mut request: reqwest_middleware::RequestBuilder,
+    max_response_bytes: Option<usize>,
+    strict_responses: bool,
+    check_status: bool,
+    accept_status: &RangeInclusive<u16>,
+) -> Result<models::Response<models::feed::Item>, crate::Error> {
+    let start = crate::time::Instant::now();
 
-        // Check if the response status is not 200 OK
-        if !response.status().is_success() {
-            return Err(crate::Error::StatusNot200(response.text().await?));
+    // Reuse the caller's attempt log if there is one, so the attempt count can be reported
+    let log = match request.extensions().get::<AttemptLog>() {
+        Some(log) => log.clone(),
+        None => {
+            let log = AttemptLog::new();
+            request.extensions().insert(log.clone());
+            log
         }
+    };
+
+    let response = request.send().await.map_err(|error| {
+        crate::Error::from(error).with_attempts(log.attempts().len() as u32, start.elapsed())
+    })?;
+
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("status", response.status().as_u16());
+
+    // Check if the response status is not one of the accepted ones
+    if !accept_status.contains(&response.status().as_u16()) {
+        let error = status_error(response, max_response_bytes).await;
+        return Err(error.with_attempts(log.attempts().len() as u32, start.elapsed()));
+    }
+
+    // Parse the response JSON into the expected structure, treating an empty body as no items
+    let response: models::Response<models::feed::Item> = read_json(response, max_response_bytes)
+        .await?
+        .unwrap_or_else(models::Response::empty);
+    if check_status {
+        response.check_status()?;
+    }
+    if strict_responses {
+        response.validate()?;
+    }
 
-        // Parse the response JSON into the expected structure
-        let response_items = response
-            .json::<models::Response<models::feed::Item>>()
-            .await?
-            .data
-            .items;
-        Ok(response_items)
+    #[cfg(feature = "tracing")]
+    {
+        let span = tracing::Span::current();
+        span.record("elapsed_ms", start.elapsed().as_millis() as u64);
+        span.record("items", response.data.items.len() as u64);
+        tracing::info!("Fetched feed");
     }
+
+    Ok(response)
+}
+
+/// Returns the URL of the request with sensitive query parameter values redacted.
+///
+/// See [`crate::reqwest_ext::redacted_url`].
+#[cfg(feature = "tracing")]
+fn request_url(request: &reqwest_middleware::RequestBuilder) -> String {
+    let Some(request) = request.try_clone().and_then(|request| request.build().ok()) else {
+        return String::new();
+    };
+    crate::reqwest_ext::redacted_url(request.url())
 }
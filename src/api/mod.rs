@@ -11,13 +11,29 @@
 //! let api = CieloApi::new("your_api_key", Some(100), Some(1000), Some(3)).unwrap();
 //! ```
 //!
+pub mod balances;
+pub mod chains;
 pub mod feed;
+pub mod pnl;
 
-use std::time::Duration;
+use std::{
+    ops::RangeInclusive,
+    sync::{Arc, OnceLock},
+    time::Duration,
+};
 
 use reqwest::header;
 
-use crate::{constants, reqwest_ext::get_retry_strategy};
+use crate::{
+    constants,
+    reqwest_ext::{
+        api_key_header, get_retry_strategy, ApiKeyAuth, ApiStats, AttemptRecorder, CircuitBreaker,
+        KeyProvider, RetryCallback, RetryContext, StatsCounters, API_KEY_HEADER,
+    },
+};
+
+#[cfg(feature = "rate-limit")]
+use crate::reqwest_ext::RateLimit;
 
 #[derive(Debug, Clone)]
 /// Represents the Cielo API client.
@@ -25,10 +41,37 @@ use crate::{constants, reqwest_ext::get_retry_strategy};
 /// This struct allows you to create a new client instance and interact with the Cielo API.
 /// It includes a [`ClientWithMiddleware`] for handling HTTP requests
 ///
+/// The client is `Send + Sync + 'static` and cheap to clone, as clones share the same connection
+/// pool, so it can be shared across tasks directly or in an `Arc`. This is checked by the test
+/// suite.
+///
 /// [`ClientWithMiddleware`]: https://docs.rs/reqwest-middleware/latest/reqwest_middleware/struct.ClientWithMiddleware.html
 pub struct CieloApi {
     /// The HTTP client with middleware for handling requests and retries.
     client: reqwest_middleware::ClientWithMiddleware,
+    /// The same HTTP client without the retry middleware, for single-attempt requests.
+    no_retry_client: reqwest_middleware::ClientWithMiddleware,
+    /// The base URL the endpoints are resolved against, always ending with a `/`.
+    base_url: String,
+    /// The maximum size of a response body in bytes, if limited.
+    max_response_bytes: Option<usize>,
+    /// Whether responses are checked against the API contract.
+    strict_responses: bool,
+    /// Whether responses whose body status is not `ok` are rejected.
+    check_status: bool,
+    /// The response statuses treated as success by the feed endpoint.
+    accept_status: RangeInclusive<u16>,
+    /// The minimum and maximum delays between page retries of [`CieloApi::get_feed_stream`].
+    page_retry_intervals: (Duration, Duration),
+    /// The cache of feed results, if enabled, shared between clones.
+    feed_cache: Option<Arc<feed::FeedCache>>,
+    /// The cache of supported chains, if enabled, shared between clones.
+    chains_cache: Option<Arc<OnceLock<Vec<String>>>>,
+    /// The request counters, shared between clones.
+    stats: Arc<StatsCounters>,
+    /// The runtime driving blocking calls, created on first use and shared between clones.
+    #[cfg(feature = "blocking")]
+    runtime: Arc<std::sync::OnceLock<tokio::runtime::Runtime>>,
     // default_params: HashMap<String, String>,
 }
 
@@ -36,6 +79,7 @@ impl CieloApi {
     /// Creates a new instance of CieloApi.
     ///
     /// This function initializes a new CieloApi client with the provided API key and optional retry parameters.
+    /// Use [`CieloApi::builder`] for more configuration options.
     ///
     /// # Arguments
     ///
@@ -46,7 +90,8 @@ impl CieloApi {
     ///
     /// # Errors
     ///
-    /// This function returns a `crate::Error` if the client cannot be built.
+    /// This function returns [`crate::Error::InvalidApiKey`] if the API key is empty, or another
+    /// `crate::Error` if the client cannot be built.
     ///
     /// # Examples
     ///
@@ -61,27 +106,576 @@ impl CieloApi {
         max_retry_interval: Option<u64>,
         max_retries: Option<u32>,
     ) -> Result<Self, crate::Error> {
-        let mut headers = header::HeaderMap::new();
+        let mut builder = Self::builder(api_key);
+        builder.min_retry_interval = min_retry_interval;
+        builder.max_retry_interval = max_retry_interval;
+        builder.max_retries = max_retries;
+        builder.build()
+    }
+
+    /// Creates a [`CieloApiBuilder`] for configuring a new CieloApi client.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - A string slice that holds the API key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cielo_rs_sdk::CieloApi;
+    ///
+    /// let api = CieloApi::builder("your_api_key")
+    ///     .max_retries(5)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn builder(api_key: &str) -> CieloApiBuilder {
+        CieloApiBuilder::new(api_key)
+    }
+
+    /// Returns the number of requests made by this client and its clones so far.
+    ///
+    /// Every HTTP attempt is counted, including retries and requests made without retrying, so
+    /// the counters can be compared against the API quota.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cielo_rs_sdk::CieloApi;
+    ///
+    /// let api = CieloApi::new("your_api_key", None, None, None).unwrap();
+    /// assert_eq!(api.stats().requests, 0);
+    /// ```
+    pub fn stats(&self) -> ApiStats {
+        self.stats.snapshot()
+    }
 
-        let mut auth_value = header::HeaderValue::from_str(api_key).unwrap();
-        auth_value.set_sensitive(true);
-        headers.insert("X-API-KEY", auth_value);
+    /// Returns the runtime driving blocking calls, creating it on first use.
+    #[cfg(feature = "blocking")]
+    fn blocking_runtime(&self) -> Result<&tokio::runtime::Runtime, crate::Error> {
+        if let Some(runtime) = self.runtime.get() {
+            return Ok(runtime);
+        }
 
-        let client = reqwest::Client::builder()
-            .default_headers(headers)
-            .timeout(Duration::from_secs(10))
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
             .build()?;
+        Ok(self.runtime.get_or_init(|| runtime))
+    }
+}
+
+/// A builder for configuring and creating a [`CieloApi`] client.
+///
+/// Every option has a sensible default, so only the API key is required.
+#[derive(Clone)]
+pub struct CieloApiBuilder {
+    /// The API key sent with every request.
+    api_key: String,
+    /// The base URL of the Cielo API.
+    base_url: String,
+    /// The minimum retry interval in milliseconds.
+    min_retry_interval: Option<u64>,
+    /// The maximum retry interval in milliseconds.
+    max_retry_interval: Option<u64>,
+    /// The maximum number of retries.
+    max_retries: Option<u32>,
+    /// The maximum time spent retrying a request, measured from its first attempt.
+    total_retry_deadline: Option<Duration>,
+    /// Whether the retry middleware is left out entirely.
+    no_retry: bool,
+    /// The number of consecutive failures opening the circuit breaker and its cooldown, if
+    /// enabled.
+    circuit_breaker: Option<(u32, Duration)>,
+    /// The callback invoked whenever an attempt is classified as retryable.
+    on_retry: Option<RetryCallback>,
+    /// The provider of fresh API keys when the current one is rejected.
+    key_provider: Option<KeyProvider>,
+    /// The `User-Agent` header sent with every request.
+    user_agent: String,
+    /// The additional headers sent with every request, as names and values.
+    headers: Vec<(String, String)>,
+    /// The maximum size of a response body in bytes, if limited.
+    max_response_bytes: Option<usize>,
+    /// Whether responses are checked against the API contract.
+    strict_responses: bool,
+    /// Whether responses whose body status is not `ok` are rejected.
+    check_status: bool,
+    /// The response statuses treated as success by the feed endpoint.
+    accept_status: RangeInclusive<u16>,
+    /// How long feed results are cached, if at all.
+    cache_ttl: Option<Duration>,
+    /// Whether the supported chains are cached after the first successful fetch.
+    cache_supported_chains: bool,
+    /// The maximum number of requests per second, if limited.
+    #[cfg(feature = "rate-limit")]
+    rate_limit: Option<std::num::NonZeroU32>,
+    /// The maximum number of idle connections kept per host.
+    #[cfg(not(target_arch = "wasm32"))]
+    pool_max_idle_per_host: Option<usize>,
+    /// The time after which idle connections are closed.
+    #[cfg(not(target_arch = "wasm32"))]
+    pool_idle_timeout: Option<Duration>,
+}
+
+impl std::fmt::Debug for CieloApiBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("CieloApiBuilder");
+        debug
+            .field("api_key", &"<redacted>")
+            .field("base_url", &self.base_url)
+            .field("min_retry_interval", &self.min_retry_interval)
+            .field("max_retry_interval", &self.max_retry_interval)
+            .field("max_retries", &self.max_retries)
+            .field("total_retry_deadline", &self.total_retry_deadline)
+            .field("no_retry", &self.no_retry)
+            .field("circuit_breaker", &self.circuit_breaker)
+            .field("on_retry", &self.on_retry.is_some())
+            .field("key_provider", &self.key_provider.is_some())
+            .field("user_agent", &self.user_agent)
+            .field("headers", &self.headers)
+            .field("max_response_bytes", &self.max_response_bytes)
+            .field("strict_responses", &self.strict_responses)
+            .field("check_status", &self.check_status)
+            .field("accept_status", &self.accept_status)
+            .field("cache_ttl", &self.cache_ttl)
+            .field("cache_supported_chains", &self.cache_supported_chains);
+        #[cfg(feature = "rate-limit")]
+        debug.field("rate_limit", &self.rate_limit);
+        #[cfg(not(target_arch = "wasm32"))]
+        debug
+            .field("pool_max_idle_per_host", &self.pool_max_idle_per_host)
+            .field("pool_idle_timeout", &self.pool_idle_timeout);
+        debug.finish()
+    }
+}
+
+impl CieloApiBuilder {
+    /// Creates a new builder with the provided API key and default options.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - A string slice that holds the API key.
+    pub fn new(api_key: &str) -> Self {
+        Self {
+            api_key: api_key.to_string(),
+            base_url: constants::URL.to_string(),
+            min_retry_interval: None,
+            max_retry_interval: None,
+            max_retries: None,
+            total_retry_deadline: None,
+            no_retry: false,
+            circuit_breaker: None,
+            on_retry: None,
+            key_provider: None,
+            user_agent: constants::USER_AGENT.to_string(),
+            headers: Vec::new(),
+            max_response_bytes: None,
+            strict_responses: false,
+            check_status: true,
+            accept_status: 200..=200,
+            cache_ttl: None,
+            cache_supported_chains: false,
+            #[cfg(feature = "rate-limit")]
+            rate_limit: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            pool_max_idle_per_host: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            pool_idle_timeout: None,
+        }
+    }
+
+    /// Sets the base URL of the Cielo API, e.g. to target a proxy or a mock server.
+    ///
+    /// A trailing `/` is appended if missing.
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        let mut base_url = base_url.to_string();
+        if !base_url.ends_with('/') {
+            base_url.push('/');
+        }
+        self.base_url = base_url;
+        self
+    }
+
+    /// Sets the minimum retry interval in milliseconds.
+    pub fn min_retry_interval(mut self, min_retry_interval: u64) -> Self {
+        self.min_retry_interval = Some(min_retry_interval);
+        self
+    }
+
+    /// Sets the maximum retry interval in milliseconds.
+    pub fn max_retry_interval(mut self, max_retry_interval: u64) -> Self {
+        self.max_retry_interval = Some(max_retry_interval);
+        self
+    }
+
+    /// Sets the maximum number of retries.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// Disables retries entirely, for tests and callers with their own retry layer.
+    ///
+    /// Unlike setting `max_retries` to 0, no retry middleware is installed at all, so failures
+    /// are returned as soon as they occur. The retry options are ignored.
+    pub fn no_retry(mut self) -> Self {
+        self.no_retry = true;
+        self
+    }
+
+    /// Sets the maximum time spent retrying a request, measured from its first attempt.
+    ///
+    /// Once a retry would start past the deadline, no further retries are made and the last
+    /// error is returned, even if `max_retries` is not exhausted. This bounds the latency of a
+    /// request regardless of the backoff. There is no deadline by default.
+    pub fn total_retry_deadline(mut self, total_retry_deadline: Duration) -> Self {
+        self.total_retry_deadline = Some(total_retry_deadline);
+        self
+    }
+
+    /// Enables a circuit breaker, to stop calling the API while it is down.
+    ///
+    /// Once `failure_threshold` consecutive requests have failed with a server error (`5xx`),
+    /// `429 Too Many Requests` or a transport failure (after their retries), requests fail
+    /// immediately with [`crate::Error::CircuitOpen`] without reaching the network. After
+    /// `cooldown`, a single probe request is sent: a success closes the breaker and a failure
+    /// opens it for another cooldown. Other errors, such as `400 Bad Request`, show that the API
+    /// is up and reset the count. The breaker is shared by the
+    /// clones of the built client, but not by other clients. Disabled by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use cielo_rs_sdk::CieloApi;
+    ///
+    /// let api = CieloApi::builder("your_api_key")
+    ///     .circuit_breaker(5, Duration::from_secs(30))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn circuit_breaker(mut self, failure_threshold: u32, cooldown: Duration) -> Self {
+        self.circuit_breaker = Some((failure_threshold, cooldown));
+        self
+    }
 
-        let retry_s = get_retry_strategy(
-            min_retry_interval.unwrap_or(constants::MIN_RETRY_INTERVAL),
-            max_retry_interval.unwrap_or(constants::MAX_RETRY_INTERVAL),
-            max_retries.unwrap_or(constants::MAX_RETRIES),
+    /// Sets a callback invoked whenever a request attempt is classified as retryable.
+    ///
+    /// The callback receives the attempt number and the status code of the failed attempt,
+    /// which is useful for metrics and logging. It is also invoked for the last attempt when
+    /// retries are exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cielo_rs_sdk::CieloApi;
+    ///
+    /// let api = CieloApi::builder("your_api_key")
+    ///     .on_retry(|context| println!("Attempt {} failed: {:?}", context.attempt, context.status))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn on_retry(mut self, on_retry: impl Fn(&RetryContext) + Send + Sync + 'static) -> Self {
+        self.on_retry = Some(Arc::new(on_retry));
+        self
+    }
+
+    /// Sets a provider of fresh API keys, for environments where keys are rotated.
+    ///
+    /// When a request is rejected with `401 Unauthorized`, the provider is invoked to get a new
+    /// key, which replaces the current one for all subsequent requests, and the request is sent
+    /// once more. If the provider fails, its error is returned as a middleware error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use cielo_rs_sdk::CieloApi;
+    /// use futures::FutureExt;
+    ///
+    /// let api = CieloApi::builder("your_api_key")
+    ///     .key_provider(Arc::new(|| async { Ok("your_new_api_key".to_string()) }.boxed()))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn key_provider(mut self, key_provider: KeyProvider) -> Self {
+        self.key_provider = Some(key_provider);
+        self
+    }
+
+    /// Sets the `User-Agent` header, e.g. to identify your application.
+    ///
+    /// Defaults to `cielo-rs-sdk/<version>`.
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = user_agent.to_string();
+        self
+    }
+
+    /// Adds a header sent with every request, e.g. one required by a proxy or gateway.
+    ///
+    /// Setting the same header twice keeps the last value, and a `User-Agent` set this way
+    /// replaces the one set with [`CieloApiBuilder::user_agent`]. The `X-API-KEY` header is
+    /// reserved: the API key is always the one passed to [`CieloApi::builder`], or provided by
+    /// [`CieloApiBuilder::key_provider`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cielo_rs_sdk::CieloApi;
+    ///
+    /// let api = CieloApi::builder("your_api_key")
+    ///     .header("X-Org-Id", "acme")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Sets the maximum size of a response body in bytes.
+    ///
+    /// Bodies are read incrementally and the request fails with
+    /// [`crate::Error::ResponseTooLarge`] as soon as the limit is exceeded, protecting long-running
+    /// services from buggy or malicious endpoints. There is no limit by default.
+    pub fn max_response_bytes(mut self, max_response_bytes: usize) -> Self {
+        self.max_response_bytes = Some(max_response_bytes);
+        self
+    }
+
+    /// Enables checking responses against the API contract, to detect when it drifts.
+    ///
+    /// In strict mode, responses whose paging information is inconsistent with their items are
+    /// rejected with [`crate::Error::InvalidResponse`] (see [`crate::models::Response::validate`]).
+    /// Disabled by default.
+    pub fn strict_responses(mut self, strict_responses: bool) -> Self {
+        self.strict_responses = strict_responses;
+        self
+    }
+
+    /// Sets whether responses whose body reports a failure are rejected.
+    ///
+    /// The API may answer `200 OK` with a `status` other than `ok` in the body, in which case
+    /// the items are partial at best. By default such responses fail with
+    /// [`crate::Error::Api`] carrying the message of the response (see
+    /// [`crate::models::Response::check_status`]). Lenient callers can disable the check to get
+    /// whatever items were returned.
+    pub fn check_status(mut self, check_status: bool) -> Self {
+        self.check_status = check_status;
+        self
+    }
+
+    /// Sets the response statuses treated as success by the feed endpoint, including
+    /// [`CieloApi::get_feed_bytes`] and [`CieloApi::health_check`].
+    ///
+    /// This is useful when the API is fronted by a proxy or gateway answering with another
+    /// status, such as `202 Accepted`, for a valid body. Responses with a status in the range are
+    /// parsed, and an empty body is treated as no items. Retries are unaffected, as they are
+    /// decided by the original status. Defaults to `200..=200`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cielo_rs_sdk::CieloApi;
+    ///
+    /// let api = CieloApi::builder("your_api_key")
+    ///     .accept_status(200..=202)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn accept_status(mut self, accept_status: RangeInclusive<u16>) -> Self {
+        self.accept_status = accept_status;
+        self
+    }
+
+    /// Enables caching feed results in memory for the given time.
+    ///
+    /// Within the TTL, [`CieloApi::get_feed`] and the methods built on it return the cached
+    /// items for identical filters (see [`feed::Filters::canonical_query`]) without a network call,
+    /// saving API quota when polling. The cache belongs to the built client and is shared by
+    /// its clones, but not by other clients. Expired entries are dropped as new results are
+    /// cached. Polling helpers, namely [`CieloApi::watch_feed`] and
+    /// [`CieloApi::latest_timestamp`], bypass the cache to see new items. Disabled by default.
+    pub fn cache_ttl(mut self, cache_ttl: Duration) -> Self {
+        self.cache_ttl = Some(cache_ttl);
+        self
+    }
+
+    /// Enables caching the supported chains after the first successful fetch.
+    ///
+    /// The list rarely changes, so [`CieloApi::get_supported_chains`] can then be called before
+    /// every query without a network call. The cache belongs to the built client and is shared
+    /// by its clones, and never expires. Disabled by default.
+    pub fn cache_supported_chains(mut self, cache_supported_chains: bool) -> Self {
+        self.cache_supported_chains = cache_supported_chains;
+        self
+    }
+
+    /// Limits the number of requests sent per second, to avoid being rate limited by the API.
+    ///
+    /// Requests, including retries, are evenly spaced: when sending one would exceed the rate,
+    /// it waits instead of failing. The limit is shared by the clones of the built client, but
+    /// not by other clients. There is no limit by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroU32;
+    ///
+    /// use cielo_rs_sdk::CieloApi;
+    ///
+    /// let api = CieloApi::builder("your_api_key")
+    ///     .rate_limit(NonZeroU32::new(5).unwrap())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "rate-limit")]
+    pub fn rate_limit(mut self, requests_per_second: std::num::NonZeroU32) -> Self {
+        self.rate_limit = Some(requests_per_second);
+        self
+    }
+
+    /// Sets the maximum number of idle connections kept per host.
+    ///
+    /// A polling bot only talks to the Cielo API, so a small pool (1 or 2) is enough to reuse
+    /// connections between polls. Defaults to reqwest's unbounded pool. Not available on wasm32,
+    /// where connections are managed by the browser.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn pool_max_idle_per_host(mut self, pool_max_idle_per_host: usize) -> Self {
+        self.pool_max_idle_per_host = Some(pool_max_idle_per_host);
+        self
+    }
+
+    /// Sets the time after which idle connections are closed.
+    ///
+    /// For a polling bot, a timeout longer than the poll interval keeps the connection warm
+    /// between polls. Defaults to reqwest's 90 seconds. Not available on wasm32, where
+    /// connections are managed by the browser.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn pool_idle_timeout(mut self, pool_idle_timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(pool_idle_timeout);
+        self
+    }
+
+    /// Builds the [`CieloApi`] client.
+    ///
+    /// Surrounding whitespace is trimmed from the API key.
+    ///
+    /// # Errors
+    ///
+    /// This function returns [`crate::Error::InvalidApiKey`] if the API key is empty or contains
+    /// characters not allowed in a header, [`crate::Error::InvalidHeader`] if the `User-Agent`
+    /// or an additional header value contains characters not allowed in a header,
+    /// [`crate::Error::InvalidHeaderName`] if an additional header name is invalid or
+    /// `X-API-KEY`, or another `crate::Error` if the client cannot be built.
+    pub fn build(self) -> Result<CieloApi, crate::Error> {
+        let auth = Arc::new(ApiKeyAuth::new(
+            api_key_header(&self.api_key)?,
+            self.key_provider,
+        ));
+
+        let mut headers = header::HeaderMap::new();
+        headers.insert(
+            header::USER_AGENT,
+            header::HeaderValue::from_str(&self.user_agent)?,
         );
+        for (name, value) in &self.headers {
+            let name = header::HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| crate::Error::InvalidHeaderName(name.clone()))?;
+            if name.as_str().eq_ignore_ascii_case(API_KEY_HEADER) {
+                return Err(crate::Error::InvalidHeaderName(format!(
+                    "{} is reserved for the API key",
+                    API_KEY_HEADER
+                )));
+            }
+            headers.insert(name, header::HeaderValue::from_str(value)?);
+        }
+
+        let client = reqwest::Client::builder().default_headers(headers);
+        // Timeouts and connection pooling are not supported by the browser fetch backend used
+        // on wasm32.
+        #[cfg(not(target_arch = "wasm32"))]
+        let client = {
+            let mut client = client.timeout(Duration::from_secs(10));
+            if let Some(pool_max_idle_per_host) = self.pool_max_idle_per_host {
+                client = client.pool_max_idle_per_host(pool_max_idle_per_host);
+            }
+            if let Some(pool_idle_timeout) = self.pool_idle_timeout {
+                client = client.pool_idle_timeout(pool_idle_timeout);
+            }
+            client
+        };
+        let client = client.build()?;
+
+        // Both clients share the connection pool, the current API key, the counters, the circuit
+        // breaker and the rate limit.
+        let stats = Arc::new(StatsCounters::default());
+        let circuit_breaker = self.circuit_breaker.map(|(failure_threshold, cooldown)| {
+            Arc::new(CircuitBreaker::new(failure_threshold, cooldown))
+        });
+        #[cfg(feature = "rate-limit")]
+        let rate_limit = self
+            .rate_limit
+            .map(|rate_limit| Arc::new(RateLimit::new(rate_limit)));
+        let mut no_retry_client = reqwest_middleware::ClientBuilder::new(client.clone());
+        if let Some(circuit_breaker) = &circuit_breaker {
+            no_retry_client = no_retry_client.with_arc(circuit_breaker.clone());
+        }
+        let no_retry_client = no_retry_client.with(AttemptRecorder::new(None, stats.clone()));
+        #[cfg(feature = "rate-limit")]
+        let no_retry_client = match &rate_limit {
+            Some(rate_limit) => no_retry_client.with_arc(rate_limit.clone()),
+            None => no_retry_client,
+        };
+        let no_retry_client = no_retry_client.with_arc(auth.clone()).build();
 
-        let client = reqwest_middleware::ClientBuilder::new(client)
-            .with(retry_s)
-            .build();
+        let mut client = reqwest_middleware::ClientBuilder::new(client);
+        if let Some(circuit_breaker) = circuit_breaker {
+            client = client.with_arc(circuit_breaker);
+        }
+        let min_retry_interval = self
+            .min_retry_interval
+            .unwrap_or(constants::MIN_RETRY_INTERVAL);
+        let max_retry_interval = self
+            .max_retry_interval
+            .unwrap_or(constants::MAX_RETRY_INTERVAL);
+        if !self.no_retry {
+            client = client.with(get_retry_strategy(
+                min_retry_interval,
+                max_retry_interval,
+                self.max_retries.unwrap_or(constants::MAX_RETRIES),
+                self.total_retry_deadline,
+            ));
+        }
+        let client = client.with(AttemptRecorder::new(self.on_retry, stats.clone()));
+        #[cfg(feature = "rate-limit")]
+        let client = match rate_limit {
+            Some(rate_limit) => client.with_arc(rate_limit),
+            None => client,
+        };
+        let client = client.with_arc(auth).build();
 
-        Ok(Self { client })
+        Ok(CieloApi {
+            client,
+            no_retry_client,
+            base_url: self.base_url,
+            max_response_bytes: self.max_response_bytes,
+            strict_responses: self.strict_responses,
+            check_status: self.check_status,
+            accept_status: self.accept_status,
+            page_retry_intervals: (
+                Duration::from_millis(min_retry_interval),
+                Duration::from_millis(max_retry_interval),
+            ),
+            feed_cache: self
+                .cache_ttl
+                .map(|cache_ttl| Arc::new(feed::FeedCache::new(cache_ttl))),
+            chains_cache: self.cache_supported_chains.then(Arc::default),
+            stats,
+            #[cfg(feature = "blocking")]
+            runtime: Arc::default(),
+        })
     }
 }
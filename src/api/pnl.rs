@@ -0,0 +1,73 @@
+//! This module provides types for the Cielo PnL (profit and loss) endpoints.
+
+use std::{convert::Infallible, fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The timeframe over which PnL is computed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Timeframe {
+    /// The last day.
+    Day,
+    /// The last 7 days.
+    Week,
+    /// The last 30 days.
+    Month,
+    /// The last year.
+    Year,
+    /// The whole history of the wallet.
+    Max,
+    /// A timeframe not known to this version of the SDK, sent verbatim.
+    Other(String),
+}
+
+impl Timeframe {
+    /// Returns the identifier used by the Cielo API for the timeframe.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Timeframe::Day => "1d",
+            Timeframe::Week => "7d",
+            Timeframe::Month => "30d",
+            Timeframe::Year => "1y",
+            Timeframe::Max => "max",
+            Timeframe::Other(timeframe) => timeframe,
+        }
+    }
+}
+
+impl fmt::Display for Timeframe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Timeframe {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "1d" => Timeframe::Day,
+            "7d" => Timeframe::Week,
+            "30d" => Timeframe::Month,
+            "1y" => Timeframe::Year,
+            "max" => Timeframe::Max,
+            other => Timeframe::Other(other.to_string()),
+        })
+    }
+}
+
+impl Serialize for Timeframe {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Timeframe {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let timeframe = String::deserialize(deserializer)?;
+        match timeframe.parse() {
+            Ok(timeframe) => Ok(timeframe),
+            Err(never) => match never {},
+        }
+    }
+}
@@ -3,9 +3,17 @@
 /// Base URL for the Cielo feed API
 pub const URL: &str = "https://feed-api.cielo.finance/api/v1/";
 
+/// Default `User-Agent` header identifying the SDK and its version
+pub const USER_AGENT: &str = concat!("cielo-rs-sdk/", env!("CARGO_PKG_VERSION"));
+
 /// Minimum retry interval in milliseconds
 pub const MIN_RETRY_INTERVAL: u64 = 500;
 /// Maximum retry interval in milliseconds
 pub const MAX_RETRY_INTERVAL: u64 = 1000;
 /// Maximum number of retries
 pub const MAX_RETRIES: u32 = 3;
+
+/// Maximum number of items per feed page allowed by the API
+pub const MAX_PAGE_LIMIT: u32 = 100;
+/// Maximum number of recent feed pages scanned when looking up a transaction by hash
+pub const TX_LOOKUP_MAX_PAGES: usize = 10;
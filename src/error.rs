@@ -1,17 +1,261 @@
 //! This module defines custom error types for the Cielo API.
 
+use std::{fmt, time::Duration};
+
+use reqwest_retry::Retryable;
+
+use crate::reqwest_ext::classify_reqwest_error;
+
 /// Custom error type for the Cielo API
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
-    /// Error originating from the reqwest library
-    #[error("Reqwest error: {0}")]
-    Reqwest(#[from] reqwest::Error),
+    /// Error originating from the reqwest library, with the context of the request
+    #[error("Reqwest error{}", context_suffix(.context))]
+    Reqwest {
+        /// The underlying error.
+        source: reqwest::Error,
+        /// The context of the failed request.
+        context: ErrorContext,
+    },
+
+    /// Error originating from the reqwest middleware, with the context of the request
+    #[error("Reqwest middleware error{}", context_suffix(.context))]
+    ReqwestMiddleware {
+        /// The underlying error.
+        source: reqwest_middleware::Error,
+        /// The context of the failed request.
+        context: ErrorContext,
+    },
+
+    /// Error indicating that the response status was not 200 OK, with the status code, the
+    /// response body and the context of the request
+    #[error("Response status {status}: {body}{}", context_suffix(.context))]
+    StatusNot200 {
+        /// The status code of the response.
+        status: u16,
+        /// The body of the response.
+        body: String,
+        /// The context of the failed request.
+        context: ErrorContext,
+    },
+
+    /// Error indicating that the API reported a failure in the body of a successful response,
+    /// with its message, or its status if there is no message
+    #[error("API error: {0}")]
+    Api(String),
+
+    /// Error indicating that the API rejected the API key, with the response body and the
+    /// context of the request
+    #[error("Unauthorized: {body}{}", context_suffix(.context))]
+    Unauthorized {
+        /// The body of the response.
+        body: String,
+        /// The context of the failed request.
+        context: ErrorContext,
+    },
+
+    /// Error indicating that the API key is empty or cannot be sent as a header
+    #[error("Invalid API key: {0}")]
+    InvalidApiKey(String),
+
+    /// Error indicating that a response violates the API contract, in strict mode
+    #[error("Invalid response: {0}")]
+    InvalidResponse(String),
+
+    /// Error indicating that a filter value is malformed
+    #[error("Invalid filter: {0}")]
+    InvalidFilter(String),
+
+    /// Error indicating that a configured header value contains invalid characters
+    #[error("Invalid header value: {0}")]
+    InvalidHeader(#[from] reqwest::header::InvalidHeaderValue),
+
+    /// Error indicating that a configured header name is invalid or reserved
+    #[error("Invalid header name: {0}")]
+    InvalidHeaderName(String),
+
+    /// Error indicating that a response body exceeded the configured maximum size, in bytes
+    #[error("Response body larger than {0} bytes")]
+    ResponseTooLarge(usize),
+
+    /// Error indicating that the request did not complete before its timeout elapsed, with the
+    /// context of the request
+    #[error("Request timed out{}", context_suffix(.context))]
+    Timeout {
+        /// The underlying error.
+        source: reqwest::Error,
+        /// The context of the failed request.
+        context: ErrorContext,
+    },
+
+    /// Error indicating that the request was not sent because the circuit breaker is open after
+    /// repeated failures
+    #[error("Circuit breaker open")]
+    CircuitOpen,
+
+    /// Error originating from an I/O operation
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Error serializing or deserializing JSON
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
 
-    /// Error originating from the reqwest middleware
-    #[error("Reqwest middleware error: {0}")]
-    ReqwestMiddleware(#[from] reqwest_middleware::Error),
+    /// Error encoding items to MessagePack
+    #[cfg(feature = "msgpack")]
+    #[error("MessagePack encode error: {0}")]
+    MsgPackEncode(#[from] rmp_serde::encode::Error),
+
+    /// Error decoding items from MessagePack
+    #[cfg(feature = "msgpack")]
+    #[error("MessagePack decode error: {0}")]
+    MsgPackDecode(#[from] rmp_serde::decode::Error),
+
+    /// Error building a Polars data frame
+    #[cfg(feature = "polars")]
+    #[error("Polars error: {0}")]
+    Polars(#[from] polars::error::PolarsError),
+}
+
+impl Error {
+    /// Returns the context of the failed request, if the error carries one.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            Self::Reqwest { context, .. }
+            | Self::ReqwestMiddleware { context, .. }
+            | Self::StatusNot200 { context, .. }
+            | Self::Unauthorized { context, .. }
+            | Self::Timeout { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the error may not recur when the request is retried later: timeouts,
+    /// connection failures, server errors (`5xx`) and `429 Too Many Requests`.
+    ///
+    /// Transport errors are classified as by the retry middleware. Other errors, such as
+    /// [`Error::Unauthorized`], errors building the request or errors raised by a middleware,
+    /// would recur on every retry.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Reqwest { source, .. } | Self::Timeout { source, .. } => {
+                matches!(classify_reqwest_error(source), Retryable::Transient)
+            }
+            Self::ReqwestMiddleware { source, .. } => match source {
+                reqwest_middleware::Error::Reqwest(source) => {
+                    matches!(classify_reqwest_error(source), Retryable::Transient)
+                }
+                reqwest_middleware::Error::Middleware(_) => false,
+            },
+            Self::StatusNot200 { status, .. } => *status >= 500 || *status == 429,
+            _ => false,
+        }
+    }
+
+    /// Records the number of attempts made and the time elapsed, if the error carries a context.
+    pub(crate) fn with_attempts(mut self, attempts: u32, elapsed: Duration) -> Self {
+        match &mut self {
+            Self::Reqwest { context, .. }
+            | Self::ReqwestMiddleware { context, .. }
+            | Self::StatusNot200 { context, .. }
+            | Self::Unauthorized { context, .. }
+            | Self::Timeout { context, .. } => {
+                context.attempts = Some(attempts);
+                context.elapsed = Some(elapsed);
+            }
+            _ => {}
+        }
+        self
+    }
+}
+
+/// Formats the context for an error message, as ` (<context>)`, or nothing if it is empty.
+fn context_suffix(context: &ErrorContext) -> String {
+    if *context == ErrorContext::default() {
+        String::new()
+    } else {
+        format!(" ({})", context)
+    }
+}
+
+/// Context of a failed request, reported alongside the error.
+///
+/// Each part is optional, as not every request path knows all of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ErrorContext {
+    /// The URL of the request, with sensitive query parameter values redacted.
+    pub url: Option<String>,
+    /// The number of attempts made, including retries.
+    pub attempts: Option<u32>,
+    /// The time elapsed between sending the request and receiving the failed response.
+    pub elapsed: Option<Duration>,
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(url) = &self.url {
+            parts.push(format!("url: {}", url));
+        }
+        if let Some(attempts) = self.attempts {
+            parts.push(format!("attempts: {}", attempts));
+        }
+        if let Some(elapsed) = self.elapsed {
+            parts.push(format!("elapsed: {:?}", elapsed));
+        }
+        f.write_str(&parts.join(", "))
+    }
+}
+
+impl ErrorContext {
+    /// Returns a context holding the redacted URL of the request that failed, if known.
+    fn from_url(url: Option<&reqwest::Url>) -> Self {
+        Self {
+            url: url.map(crate::reqwest_ext::redacted_url),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        let context = ErrorContext::from_url(error.url());
+        if error.is_timeout() {
+            Self::Timeout {
+                source: error,
+                context,
+            }
+        } else {
+            Self::Reqwest {
+                source: error,
+                context,
+            }
+        }
+    }
+}
 
-    /// Error indicating that the response status was not 200 OK
-    #[error("Response status not 200: {0}")]
-    StatusNot200(String),
+impl From<reqwest_middleware::Error> for Error {
+    fn from(error: reqwest_middleware::Error) -> Self {
+        match error {
+            reqwest_middleware::Error::Reqwest(error) if error.is_timeout() => Self::Timeout {
+                context: ErrorContext::from_url(error.url()),
+                source: error,
+            },
+            reqwest_middleware::Error::Middleware(error)
+                if error.is::<crate::reqwest_ext::CircuitOpenError>() =>
+            {
+                Self::CircuitOpen
+            }
+            error => {
+                let url = match &error {
+                    reqwest_middleware::Error::Reqwest(error) => error.url(),
+                    reqwest_middleware::Error::Middleware(_) => None,
+                };
+                Self::ReqwestMiddleware {
+                    context: ErrorContext::from_url(url),
+                    source: error,
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,183 @@
+//! This module provides helpers for exporting feed items to other formats and reading them back.
+//!
+//! Binary formats are gated behind their own feature flags.
+
+use std::io::{BufRead, Write};
+
+#[cfg(feature = "polars")]
+use crate::models::feed::FlatItem;
+use crate::models::feed::Item;
+
+/// The magic bytes every gzip stream starts with.
+#[cfg(feature = "gzip")]
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Lazily reads feed items from newline-delimited JSON, one item per line.
+///
+/// Blank lines are skipped. With the `gzip` feature enabled, gzip-compressed input is
+/// detected from its magic bytes and decompressed on the fly, so very large exports can be
+/// replayed without loading them into memory.
+///
+/// Each line yields either the parsed item or the error encountered while reading or parsing it,
+/// so a single malformed line does not stop the iteration.
+///
+/// # Examples
+///
+/// ```
+/// use cielo_rs_sdk::export::read_feed_ndjson;
+///
+/// let items: Vec<_> = read_feed_ndjson("\n".as_bytes()).collect();
+/// assert!(items.is_empty());
+/// ```
+pub fn read_feed_ndjson<'a, R: BufRead + 'a>(
+    reader: R,
+) -> impl Iterator<Item = Result<Item, crate::Error>> + 'a {
+    #[cfg(feature = "gzip")]
+    let reader = maybe_decompress(reader);
+
+    reader.lines().filter_map(|line| match line {
+        Ok(line) if line.trim().is_empty() => None,
+        Ok(line) => Some(serde_json::from_str::<Item>(&line).map_err(crate::Error::from)),
+        Err(error) => Some(Err(error.into())),
+    })
+}
+
+/// Writes feed items as newline-delimited JSON, one item per line.
+///
+/// The output can be read back with [`read_feed_ndjson`]. The writer is not flushed, so wrap it
+/// in a [`std::io::BufWriter`] when writing many items to a file.
+///
+/// # Errors
+///
+/// This function returns a `crate::Error` if an item cannot be serialized or written. Items
+/// before the failing one have already been written.
+///
+/// # Examples
+///
+/// ```
+/// use cielo_rs_sdk::export::to_ndjson;
+///
+/// let mut buffer = Vec::new();
+/// to_ndjson(&[], &mut buffer).unwrap();
+/// assert!(buffer.is_empty());
+/// ```
+pub fn to_ndjson<W: Write>(items: &[Item], mut writer: W) -> Result<(), crate::Error> {
+    for item in items {
+        serde_json::to_writer(&mut writer, item)?;
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+/// Wraps the reader in a gzip decoder if its content starts with the gzip magic bytes.
+#[cfg(feature = "gzip")]
+fn maybe_decompress<'a, R: BufRead + 'a>(mut reader: R) -> Box<dyn BufRead + 'a> {
+    let is_gzip = matches!(reader.fill_buf(), Ok(buf) if buf.starts_with(&GZIP_MAGIC));
+    if is_gzip {
+        Box::new(std::io::BufReader::new(
+            flate2::bufread::MultiGzDecoder::new(reader),
+        ))
+    } else {
+        Box::new(reader)
+    }
+}
+
+/// Serializes a page of feed items to MessagePack.
+///
+/// Items are encoded as maps keyed by field name rather than as positional arrays,
+/// so that the untagged [`Item`] enum can be decoded back.
+///
+/// # Errors
+///
+/// This function returns a `crate::Error` if an item cannot be serialized.
+///
+/// # Examples
+///
+/// ```
+/// use cielo_rs_sdk::export::{items_from_msgpack, items_to_msgpack};
+///
+/// let bytes = items_to_msgpack(&[]).unwrap();
+/// assert!(items_from_msgpack(&bytes).unwrap().is_empty());
+/// ```
+#[cfg(feature = "msgpack")]
+pub fn items_to_msgpack(items: &[Item]) -> Result<Vec<u8>, crate::Error> {
+    Ok(rmp_serde::to_vec_named(items)?)
+}
+
+/// Deserializes a page of feed items from MessagePack produced by [`items_to_msgpack`].
+///
+/// # Errors
+///
+/// This function returns a `crate::Error` if the bytes are not a valid MessagePack list of items.
+#[cfg(feature = "msgpack")]
+pub fn items_from_msgpack(bytes: &[u8]) -> Result<Vec<Item>, crate::Error> {
+    Ok(rmp_serde::from_slice(bytes)?)
+}
+
+/// Builds a Polars data frame with one row per item, from their [`FlatItem`] view.
+///
+/// The columns are `wallet`, `wallet_label`, `tx_hash`, `tx_type`, `chain`, `timestamp`,
+/// `block`, `usd_value` and `counterparty`, in that order. Fields specific to a variant are
+/// left out, so every item fits the same schema. Missing values are null.
+///
+/// # Errors
+///
+/// This function returns a `crate::Error` if the data frame cannot be built.
+///
+/// # Examples
+///
+/// ```
+/// use cielo_rs_sdk::export::to_dataframe;
+///
+/// let df = to_dataframe(&[]).unwrap();
+/// assert_eq!(df.height(), 0);
+/// ```
+#[cfg(feature = "polars")]
+pub fn to_dataframe(items: &[Item]) -> Result<polars::frame::DataFrame, crate::Error> {
+    use polars::prelude::{DataFrame, NamedFrom, Series};
+
+    let rows: Vec<FlatItem> = items.iter().map(FlatItem::from).collect();
+    let column = |name: &str, values: Vec<String>| Series::new(name.into(), values);
+
+    Ok(DataFrame::new(vec![
+        column(
+            "wallet",
+            rows.iter().map(|row| row.wallet.clone()).collect(),
+        ),
+        column(
+            "wallet_label",
+            rows.iter().map(|row| row.wallet_label.clone()).collect(),
+        ),
+        column(
+            "tx_hash",
+            rows.iter().map(|row| row.tx_hash.clone()).collect(),
+        ),
+        column(
+            "tx_type",
+            rows.iter().map(|row| row.tx_type.to_string()).collect(),
+        ),
+        column("chain", rows.iter().map(|row| row.chain.clone()).collect()),
+        Series::new(
+            "timestamp".into(),
+            rows.iter().map(|row| row.timestamp).collect::<Vec<u64>>(),
+        ),
+        Series::new(
+            "block".into(),
+            rows.iter()
+                .map(|row| row.block)
+                .collect::<Vec<Option<u64>>>(),
+        ),
+        Series::new(
+            "usd_value".into(),
+            rows.iter()
+                .map(|row| row.usd_value)
+                .collect::<Vec<Option<f64>>>(),
+        ),
+        Series::new(
+            "counterparty".into(),
+            rows.iter()
+                .map(|row| row.counterparty.clone())
+                .collect::<Vec<Option<String>>>(),
+        ),
+    ])?)
+}
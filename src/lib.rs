@@ -11,6 +11,12 @@ This is not an official SDK. It is a personal project for learning Rust and API
 
 - Configurable retry strategy for API requests.
 - Fetch feed data with various filters.
+- Blocking API for callers without an async runtime (`blocking` feature).
+- Cooperative cancellation of long-running fetches (`cancellation` feature).
+- Exact decimal amounts for accounting, parsed from the numbers as written in responses (`decimal` feature).
+- Export feed items to NDJSON or MessagePack (`msgpack` feature) and replay NDJSON exports (optionally gzip-compressed with the `gzip` feature).
+- Export feed items to a Polars `DataFrame` for analysis (`polars` feature).
+- Client-side rate limiting to avoid `429 Too Many Requests` responses (`rate-limit` feature).
 
 ## Configuration
 
@@ -20,6 +26,17 @@ You can configure the retry strategy by providing optional parameters when creat
 - `max_retry_interval`: Maximum retry interval in milliseconds.
 - `max_retries`: Maximum number of retries.
 
+Use [`CieloApi::builder`] to observe retries through a callback with `on_retry`. With the `tracing`
+feature enabled, a `tracing` event is also emitted for every retryable attempt, and feed requests
+are wrapped in a `get_feed` span recording the URL, status, elapsed time and item count.
+
+## WebAssembly
+
+The crate compiles for `wasm32-unknown-unknown` with its default features, using reqwest's
+browser `fetch` backend. On that target the 10 second client-wide timeout and
+[`CieloApi::get_feed_with_timeout`] are unavailable, as `fetch` does not support them, and the
+`blocking` feature is not supported. Everything else, including retries, works the same.
+
 ## Usage
 
 <div class="warning">
@@ -57,6 +74,7 @@ let filters = api::feed::Filters {
     from_timestamp: None,
     to_timestamp: None,
     include_market_cap: Some(true),
+    ..Default::default()
 };
 let feed = cielo_api.get_feed(filters).await.unwrap();
 # }
@@ -72,10 +90,15 @@ let feed = cielo_api.get_feed(filters).await.unwrap();
 mod constants;
 mod error;
 mod reqwest_ext;
+mod time;
 
+pub mod analysis;
 pub mod api;
+pub mod export;
 pub mod models;
 
 // Re-export the CieloApi struct
 pub use api::CieloApi;
-pub use error::Error;
+pub use error::{Error, ErrorContext};
+pub use reqwest_ext::{ApiStats, KeyProvider, RetryAttempt, RetryContext};
+pub use time::{Clock, SystemClock};
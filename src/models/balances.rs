@@ -0,0 +1,20 @@
+//! This module contains the data structures for the Cielo balances endpoint response.
+
+use serde::{Deserialize, Serialize};
+
+/// Represents the balance of a token held by a wallet.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TokenBalance {
+    /// The symbol of the token.
+    pub token_symbol: String,
+    /// The address of the token contract.
+    pub token_address: String,
+    /// The amount of the token held.
+    pub balance: f64,
+    /// The USD value of the amount held.
+    pub balance_usd: f64,
+    /// The price of the token in USD.
+    pub price_usd: f64,
+    /// The blockchain network the token is held on.
+    pub chain: String,
+}
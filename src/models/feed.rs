@@ -1,9 +1,28 @@
 //! This module contains the data structures for the Cielo feed endpoint response.
 
-use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::Infallible,
+    fmt,
+    str::FromStr,
+};
+
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+
+use crate::api::feed::{Chain, TxType};
+
+pub use crate::export::to_ndjson;
 
 /// Represents an item in the feed.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+///
+/// Items are deserialized as the variant named by their `tx_type`. If `tx_type` is missing or
+/// unknown, or the payload lacks a field of the named variant, the variants are tried in
+/// declaration order instead and the first one whose fields are all present wins. Several
+/// variants share most fields (e.g. an NFT sweep payload also fits [`Item::NftTrade`], and a
+/// flashloan payload [`Item::Reward`]), so `tx_type` takes precedence over this order.
+///
+/// Items serialize as their inner struct, without an enclosing tag.
+#[derive(Debug, Serialize, Clone, PartialEq)]
 #[serde(untagged)]
 pub enum Item {
     /// A swap transaction.
@@ -48,8 +67,513 @@ pub enum Item {
     NftSweep(NftSweep),
 }
 
+/// The transaction types of the [`Item`] variants, in declaration order.
+const VARIANT_ORDER: [TxType; 20] = [
+    TxType::Swap,
+    TxType::Lp,
+    TxType::Transfer,
+    TxType::Lending,
+    TxType::NftMint,
+    TxType::NftTrade,
+    TxType::NftTransfer,
+    TxType::NftLending,
+    TxType::Bridge,
+    TxType::ContractInteraction,
+    TxType::Wrap,
+    TxType::SudoPool,
+    TxType::Reward,
+    TxType::Staking,
+    TxType::Perp,
+    TxType::Flashloan,
+    TxType::ContractCreation,
+    TxType::NftLiquidation,
+    TxType::Option,
+    TxType::NftSweep,
+];
+
+impl<'de> Deserialize<'de> for Item {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+
+        let tx_type = value
+            .get("tx_type")
+            .and_then(serde_json::Value::as_str)
+            .and_then(|tx_type| TxType::from_str(tx_type).ok());
+        if let Some(item) = tx_type.and_then(|tx_type| Item::deserialize_as(&tx_type, &value).ok())
+        {
+            return Ok(item);
+        }
+
+        VARIANT_ORDER
+            .iter()
+            .find_map(|tx_type| Item::deserialize_as(tx_type, &value).ok())
+            .ok_or_else(|| D::Error::custom("data did not match any variant of Item"))
+    }
+}
+
+impl Item {
+    /// Deserializes the value as the variant of the given transaction type.
+    fn deserialize_as(
+        tx_type: &TxType,
+        value: &serde_json::Value,
+    ) -> Result<Self, serde_json::Error> {
+        match tx_type {
+            TxType::Swap => Swap::deserialize(value).map(Item::Swap),
+            TxType::Lp => Lp::deserialize(value).map(Item::Lp),
+            TxType::Transfer => Transfer::deserialize(value).map(Item::Transfer),
+            TxType::Lending => Lending::deserialize(value).map(Item::Lending),
+            TxType::NftMint => NftMint::deserialize(value).map(Item::NftMint),
+            TxType::NftTrade => NftTrade::deserialize(value).map(Item::NftTrade),
+            TxType::NftTransfer => NftTransfer::deserialize(value).map(Item::NftTransfer),
+            TxType::NftLending => NftLending::deserialize(value).map(Item::NftLending),
+            TxType::Bridge => Bridge::deserialize(value).map(Item::Bridge),
+            TxType::ContractInteraction => {
+                ContractInteraction::deserialize(value).map(Item::ContractInteraction)
+            }
+            TxType::Wrap => Wrap::deserialize(value).map(Item::Wrap),
+            TxType::SudoPool => SudoPool::deserialize(value).map(Item::SudoPool),
+            TxType::Reward => Reward::deserialize(value).map(Item::Reward),
+            TxType::Staking => Staking::deserialize(value).map(Item::Staking),
+            TxType::Perp => Perp::deserialize(value).map(Item::Perp),
+            TxType::Flashloan => Flashloan::deserialize(value).map(Item::Flashloan),
+            TxType::ContractCreation => {
+                ContractCreation::deserialize(value).map(Item::ContractCreation)
+            }
+            TxType::NftLiquidation => NftLiquidation::deserialize(value).map(Item::NftLiquidation),
+            TxType::Option => OptionType::deserialize(value).map(Item::Option),
+            TxType::NftSweep => NftSweep::deserialize(value).map(Item::NftSweep),
+        }
+    }
+}
+
+/// Expands to a match over every [`Item`] variant, binding the inner struct to `$inner`.
+///
+/// Useful for accessing the fields shared by all transaction types.
+macro_rules! match_item {
+    ($item:expr, $inner:ident => $body:expr) => {
+        match $item {
+            Item::Swap($inner) => $body,
+            Item::Lp($inner) => $body,
+            Item::Transfer($inner) => $body,
+            Item::Lending($inner) => $body,
+            Item::NftMint($inner) => $body,
+            Item::NftTrade($inner) => $body,
+            Item::NftTransfer($inner) => $body,
+            Item::NftLending($inner) => $body,
+            Item::Bridge($inner) => $body,
+            Item::ContractInteraction($inner) => $body,
+            Item::Wrap($inner) => $body,
+            Item::SudoPool($inner) => $body,
+            Item::Reward($inner) => $body,
+            Item::Staking($inner) => $body,
+            Item::Perp($inner) => $body,
+            Item::Flashloan($inner) => $body,
+            Item::ContractCreation($inner) => $body,
+            Item::NftLiquidation($inner) => $body,
+            Item::Option($inner) => $body,
+            Item::NftSweep($inner) => $body,
+        }
+    };
+}
+
+impl Item {
+    /// Returns the wallet address the item belongs to.
+    pub fn wallet(&self) -> &str {
+        match_item!(self, item => &item.wallet)
+    }
+
+    /// Returns the readable label of the wallet the item belongs to.
+    pub fn wallet_label(&self) -> &str {
+        match_item!(self, item => &item.wallet_label)
+    }
+
+    /// Returns a display name for the wallet the item belongs to, preferring its label.
+    ///
+    /// See [`display_name`].
+    pub fn wallet_display(&self) -> String {
+        display_name(self.wallet_label(), self.wallet())
+    }
+
+    /// Returns the hash of the transaction.
+    pub fn tx_hash(&self) -> &str {
+        match_item!(self, item => &item.tx_hash)
+    }
+
+    /// Returns the raw transaction type string, as returned by the API.
+    pub fn tx_type(&self) -> &str {
+        match_item!(self, item => &item.tx_type)
+    }
+
+    /// Returns the originating address of the transaction.
+    pub fn from(&self) -> &str {
+        match_item!(self, item => &item.from)
+    }
+
+    /// Returns the destination address of the transaction, for variants that have one.
+    pub fn to(&self) -> Option<&str> {
+        match self {
+            Item::Lp(_)
+            | Item::Lending(_)
+            | Item::Reward(_)
+            | Item::Flashloan(_)
+            | Item::ContractCreation(_) => None,
+            Item::Swap(item) => Some(&item.to),
+            Item::Transfer(item) => Some(&item.to),
+            Item::NftMint(item) => Some(&item.to),
+            Item::NftTrade(item) => Some(&item.to),
+            Item::NftTransfer(item) => Some(&item.to),
+            Item::NftLending(item) => Some(&item.to),
+            Item::Bridge(item) => Some(&item.to),
+            Item::ContractInteraction(item) => Some(&item.to),
+            Item::Wrap(item) => Some(&item.to),
+            Item::SudoPool(item) => Some(&item.to),
+            Item::Staking(item) => Some(&item.to),
+            Item::Perp(item) => Some(&item.to),
+            Item::NftLiquidation(item) => Some(&item.to),
+            Item::Option(item) => Some(&item.to),
+            Item::NftSweep(item) => Some(&item.to),
+        }
+    }
+
+    /// Returns the blockchain network the transaction occurred on.
+    pub fn chain(&self) -> &str {
+        match_item!(self, item => &item.chain)
+    }
+
+    /// Returns the index of the item within its transaction.
+    pub fn index(&self) -> u32 {
+        match_item!(self, item => item.index)
+    }
+
+    /// Returns whether the item is flagged as the first interaction of the wallet, e.g. its
+    /// first purchase of a token.
+    ///
+    /// Only swaps, NFT trades and NFT sweeps report the flag; other items return `false`.
+    pub fn is_first_interaction(&self) -> bool {
+        match self {
+            Item::Swap(item) => item.first_interaction,
+            Item::NftTrade(item) => item.first_interaction,
+            Item::NftSweep(item) => item.first_interaction,
+            _ => false,
+        }
+    }
+
+    /// Returns the number of the block the transaction is included in, if reported.
+    pub fn block(&self) -> Option<u64> {
+        match self {
+            Item::SudoPool(item) => item.block,
+            Item::Swap(item) => Some(item.block),
+            Item::Lp(item) => Some(item.block),
+            Item::Transfer(item) => Some(item.block),
+            Item::Lending(item) => Some(item.block),
+            Item::NftMint(item) => Some(item.block),
+            Item::NftTrade(item) => Some(item.block),
+            Item::NftTransfer(item) => Some(item.block),
+            Item::NftLending(item) => Some(item.block),
+            Item::Bridge(item) => Some(item.block),
+            Item::ContractInteraction(item) => Some(item.block),
+            Item::Wrap(item) => Some(item.block),
+            Item::Reward(item) => Some(item.block),
+            Item::Staking(item) => Some(item.block),
+            Item::Perp(item) => Some(item.block),
+            Item::Flashloan(item) => Some(item.block),
+            Item::ContractCreation(item) => Some(item.block),
+            Item::NftLiquidation(item) => Some(item.block),
+            Item::Option(item) => Some(item.block),
+            Item::NftSweep(item) => Some(item.block),
+        }
+    }
+
+    /// Returns the counterparty of the item's wallet, if any.
+    ///
+    /// This is the destination address when the wallet sent the transaction, and the originating
+    /// address otherwise. Returns `None` when the counterparty is the wallet itself or unknown.
+    pub fn counterparty(&self) -> Option<&str> {
+        let counterparty = if addresses_eq(self.from(), self.wallet()) {
+            self.to()?
+        } else {
+            self.from()
+        };
+        (!addresses_eq(counterparty, self.wallet())).then_some(counterparty)
+    }
+
+    /// Returns the UNIX timestamp of the transaction.
+    pub fn timestamp(&self) -> u64 {
+        match_item!(self, item => item.timestamp)
+    }
+
+    /// Returns the transaction type of the item, derived from its variant.
+    ///
+    /// Unlike [`Item::tx_type`], this does not depend on the raw string returned by the API.
+    pub fn tx_type_enum(&self) -> TxType {
+        match self {
+            Item::Swap(_) => TxType::Swap,
+            Item::Lp(_) => TxType::Lp,
+            Item::Transfer(_) => TxType::Transfer,
+            Item::Lending(_) => TxType::Lending,
+            Item::NftMint(_) => TxType::NftMint,
+            Item::NftTrade(_) => TxType::NftTrade,
+            Item::NftTransfer(_) => TxType::NftTransfer,
+            Item::NftLending(_) => TxType::NftLending,
+            Item::Bridge(_) => TxType::Bridge,
+            Item::ContractInteraction(_) => TxType::ContractInteraction,
+            Item::Wrap(_) => TxType::Wrap,
+            Item::SudoPool(_) => TxType::SudoPool,
+            Item::Reward(_) => TxType::Reward,
+            Item::Staking(_) => TxType::Staking,
+            Item::Perp(_) => TxType::Perp,
+            Item::Flashloan(_) => TxType::Flashloan,
+            Item::ContractCreation(_) => TxType::ContractCreation,
+            Item::NftLiquidation(_) => TxType::NftLiquidation,
+            Item::Option(_) => TxType::Option,
+            Item::NftSweep(_) => TxType::NftSweep,
+        }
+    }
+
+    /// Returns the USD value of the item, if the API reports one.
+    ///
+    /// For swaps and Sudo Pool transactions this is the USD value of the first token, for LP
+    /// transactions the combined value of both tokens, and for NFT trades, sweeps, lending and
+    /// liquidations the USD price. NFT transfers, contract interactions and option events carry
+    /// no USD value and return `None`.
+    pub fn usd_value(&self) -> Option<f64> {
+        match self {
+            Item::Swap(item) => Some(item.token0_amount_usd),
+            Item::Lp(item) => Some(item.token0_amount_usd + item.token1_amount_usd),
+            Item::Transfer(item) => Some(item.amount_usd),
+            Item::Lending(item) => Some(item.amount_usd),
+            Item::NftMint(item) => Some(item.value_usd),
+            Item::NftTrade(item) => Some(item.price_usd),
+            Item::NftLending(item) => Some(item.price_usd),
+            Item::Bridge(item) => Some(item.amount_usd),
+            Item::Wrap(item) => Some(item.amount_usd),
+            Item::SudoPool(item) => Some(item.token0_amount_usd),
+            Item::Reward(item) => Some(item.amount_usd),
+            Item::Staking(item) => Some(item.amount_usd),
+            Item::Perp(item) => Some(item.amount_usd),
+            Item::Flashloan(item) => Some(item.amount_usd),
+            Item::ContractCreation(item) => Some(item.amount_usd),
+            Item::NftLiquidation(item) => Some(item.price_usd),
+            Item::NftSweep(item) => Some(item.price_usd),
+            Item::NftTransfer(_) | Item::ContractInteraction(_) | Item::Option(_) => None,
+        }
+    }
+
+    /// Returns the symbol of the currency the item's values are denominated in.
+    ///
+    /// This is the explicit `currency_symbol` for variants that carry one (NFT mints, trades,
+    /// sweeps, lending and liquidations), and the native currency of the item's chain otherwise.
+    /// Returns `None` if neither is known.
+    pub fn currency_symbol(&self) -> Option<&str> {
+        match self {
+            Item::NftMint(item) => Some(&item.currency_symbol),
+            Item::NftTrade(item) => Some(&item.currency_symbol),
+            Item::NftLending(item) => Some(&item.currency_symbol),
+            Item::NftLiquidation(item) => Some(&item.currency_symbol),
+            Item::NftSweep(item) => Some(&item.currency_symbol),
+            _ => self
+                .chain()
+                .parse::<Chain>()
+                .ok()
+                .and_then(|chain| chain.info())
+                .map(|info| info.native_symbol),
+        }
+    }
+}
+
+/// Defines an `is_*` predicate and an `as_*` accessor on [`Item`] for each listed variant.
+macro_rules! item_variant_methods {
+    ($($variant:ident($ty:ty) => $is:ident, $as:ident, $noun:literal;)+) => {
+        impl Item {
+            $(
+                #[doc = concat!("Returns `true` if the item is ", $noun, ".")]
+                pub fn $is(&self) -> bool {
+                    matches!(self, Item::$variant(_))
+                }
+
+                #[doc = concat!("Returns the inner struct if the item is ", $noun, ".")]
+                pub fn $as(&self) -> Option<&$ty> {
+                    match self {
+                        Item::$variant(item) => Some(item),
+                        _ => None,
+                    }
+                }
+            )+
+        }
+    };
+}
+
+item_variant_methods! {
+    Swap(Swap) => is_swap, as_swap, "a swap";
+    Lp(Lp) => is_lp, as_lp, "a liquidity pool (LP) transaction";
+    Transfer(Transfer) => is_transfer, as_transfer, "a transfer";
+    Lending(Lending) => is_lending, as_lending, "a lending transaction";
+    NftMint(NftMint) => is_nft_mint, as_nft_mint, "an NFT mint";
+    NftTrade(NftTrade) => is_nft_trade, as_nft_trade, "an NFT trade";
+    NftTransfer(NftTransfer) => is_nft_transfer, as_nft_transfer, "an NFT transfer";
+    NftLending(NftLending) => is_nft_lending, as_nft_lending, "an NFT lending transaction";
+    Bridge(Bridge) => is_bridge, as_bridge, "a bridge transaction";
+    ContractInteraction(ContractInteraction) =>
+        is_contract_interaction, as_contract_interaction, "a contract interaction";
+    Wrap(Wrap) => is_wrap, as_wrap, "a wrap transaction";
+    SudoPool(SudoPool) => is_sudo_pool, as_sudo_pool, "a Sudo Pool transaction";
+    Reward(Reward) => is_reward, as_reward, "a reward transaction";
+    Staking(Staking) => is_staking, as_staking, "a staking transaction";
+    Perp(Perp) => is_perp, as_perp, "a perpetual transaction";
+    Flashloan(Flashloan) => is_flashloan, as_flashloan, "a flashloan transaction";
+    ContractCreation(ContractCreation) =>
+        is_contract_creation, as_contract_creation, "a contract creation";
+    NftLiquidation(NftLiquidation) => is_nft_liquidation, as_nft_liquidation, "an NFT liquidation";
+    Option(OptionType) => is_option, as_option, "an option event";
+    NftSweep(NftSweep) => is_nft_sweep, as_nft_sweep, "an NFT sweep";
+}
+
+/// Defines an enum for a string field with known values, keeping unknown ones in `Other`.
+///
+/// The enum displays as, parses from and deserializes from the raw string.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident => $value:literal,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+        #[serde(from = "String")]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)+
+            /// A value not known to this version of the SDK.
+            Other(String),
+        }
+
+        impl $name {
+            /// Returns the raw string value.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)+
+                    Self::Other(value) => value,
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => Self::$variant,)+
+                    other => Self::Other(other.to_string()),
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                Self::from(value.as_str())
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self::from(s))
+            }
+        }
+    };
+}
+
+string_enum! {
+    /// The direction of a bridge transaction.
+    BridgeDirection {
+        /// Funds withdrawn from the bridge to the destination chain.
+        Withdraw => "withdraw",
+        /// Funds deposited into the bridge from the source chain.
+        Deposit => "deposit",
+    }
+}
+
+string_enum! {
+    /// The action of a liquidity pool transaction.
+    LpAction {
+        /// Liquidity added to the pool.
+        Add => "add",
+        /// Liquidity removed from the pool.
+        Remove => "remove",
+    }
+}
+
+string_enum! {
+    /// The action of a wrap transaction.
+    WrapAction {
+        /// Native tokens wrapped, e.g. ETH to WETH.
+        Wrap => "wrap",
+        /// Wrapped tokens unwrapped, e.g. WETH to ETH.
+        Unwrap => "unwrap",
+    }
+}
+
+string_enum! {
+    /// The action of a staking transaction.
+    StakeAction {
+        /// Tokens staked.
+        Stake => "stake",
+        /// Tokens unstaked.
+        Unstake => "unstake",
+    }
+}
+
+string_enum! {
+    /// The direction of an option.
+    OptionDirection {
+        /// An option to buy the asset at the strike price.
+        Call => "call",
+        /// An option to sell the asset at the strike price.
+        Put => "put",
+    }
+}
+
+string_enum! {
+    /// The status of an option position.
+    OptionPositionStatus {
+        /// A position that is still held.
+        Open => "open",
+        /// A position that was exercised, sold or expired.
+        Closed => "closed",
+    }
+}
+
+string_enum! {
+    /// The standard of a token contract.
+    TokenStandard {
+        /// An Ethereum fungible token.
+        Erc20 => "ERC20",
+        /// An Ethereum non-fungible token.
+        Erc721 => "ERC721",
+        /// An Ethereum multi-token, fungible or not.
+        Erc1155 => "ERC1155",
+        /// A Solana token.
+        Spl => "SPL",
+    }
+}
+
+string_enum! {
+    /// The direction of a perpetual trade.
+    PerpDirection {
+        /// A position profiting from a rising price.
+        Long => "long",
+        /// A position profiting from a falling price.
+        Short => "short",
+    }
+}
+
 /// Represents a swap transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Swap {
     /// The wallet address that initiated the swap transaction.
     pub wallet: String,
@@ -108,18 +632,19 @@ pub struct Swap {
 }
 
 /// Represents the market cap and liquidity details of a token.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct TokenMarketCap {
     /// The address of the token.
     pub token_address: String,
-    /// The market capitalization of the token.
-    pub market_cap: f64,
-    /// The liquidity of the token.
-    pub liquidity: f64,
+    /// The market capitalization of the token, if known. Reported as `null` for some illiquid
+    /// tokens.
+    pub market_cap: Option<f64>,
+    /// The liquidity of the token, if known. Reported as `null` for some illiquid tokens.
+    pub liquidity: Option<f64>,
 }
 
 /// Represents a liquidity pool (LP) transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Lp {
     /// The wallet address participating in the LP transaction.
     pub wallet: String,
@@ -177,8 +702,15 @@ pub struct Lp {
     pub upper_bound: f64,
 }
 
+impl Lp {
+    /// Returns the typed action of the LP transaction, parsed from `r#type`.
+    pub fn lp_action(&self) -> LpAction {
+        LpAction::from(self.r#type.as_str())
+    }
+}
+
 /// Represents a transfer transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Transfer {
     /// The wallet address involved in the transfer transaction.
     pub wallet: String,
@@ -222,8 +754,35 @@ pub struct Transfer {
     pub token_market_cap: Option<TokenMarketCap>,
 }
 
+impl Transfer {
+    /// Returns the typed standard of the token, parsed from `r#type`.
+    pub fn token_standard(&self) -> TokenStandard {
+        TokenStandard::from(self.r#type.as_str())
+    }
+
+    /// Returns a display name for the sender or recipient of the transfer.
+    ///
+    /// This is the counterparty's label when present, and its shortened address otherwise. See
+    /// [`display_name`].
+    pub fn counterparty_display(&self, direction: Direction) -> String {
+        match direction {
+            Direction::From => display_name(&self.from_label, &self.from),
+            Direction::To => display_name(&self.to_label, &self.to),
+        }
+    }
+}
+
+/// The side of a transaction a counterparty is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// The originating address.
+    From,
+    /// The destination address.
+    To,
+}
+
 /// Represents a lending transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Lending {
     /// The wallet address associated with the transaction.
     pub wallet: String,
@@ -270,7 +829,7 @@ pub struct Lending {
 }
 
 /// Represents an NFT minting transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct NftMint {
     /// The wallet address involved in the NFT minting transaction.
     pub wallet: String,
@@ -324,8 +883,15 @@ pub struct NftMint {
     pub value_usd: f64,
 }
 
+impl NftMint {
+    /// Returns the typed standard of the NFT contract, parsed from `contract_type`.
+    pub fn token_standard(&self) -> TokenStandard {
+        TokenStandard::from(self.contract_type.as_str())
+    }
+}
+
 /// Represents an NFT trading transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct NftTrade {
     /// The wallet address involved in the NFT trading transaction.
     pub wallet: String,
@@ -369,7 +935,8 @@ pub struct NftTrade {
     pub price: f64,
     /// The equivalent USD value of the NFT trade.
     pub price_usd: f64,
-    /// The profit earned from the trade. This may be zero in some transactions.
+    /// The profit earned from the trade, denominated in the trade currency (see `currency_symbol`),
+    /// not in USD. This may be zero in some transactions. Use `profit_usd` for a USD value.
     pub profit: f64,
     /// The symbol of the currency used in the trade, such as WETH or ETH.
     pub currency_symbol: String,
@@ -385,8 +952,21 @@ pub struct NftTrade {
     pub bid_accepted: bool,
 }
 
+impl NftTrade {
+    /// Returns the profit of the trade converted to USD.
+    ///
+    /// `profit` is denominated in the trade currency, so it is converted using the exchange rate
+    /// implied by `price_usd / price`. Returns `None` if `price` is zero, as the rate is unknown.
+    pub fn profit_usd(&self) -> Option<f64> {
+        if self.price == 0.0 {
+            return None;
+        }
+        Some(self.profit * self.price_usd / self.price)
+    }
+}
+
 /// Represents an NFT transfer transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct NftTransfer {
     /// The wallet address involved in the NFT transfer transaction.
     pub wallet: String,
@@ -434,8 +1014,15 @@ pub struct NftTransfer {
     pub value: f64,
 }
 
+impl NftTransfer {
+    /// Returns the typed standard of the NFT contract, parsed from `contract_type`.
+    pub fn token_standard(&self) -> TokenStandard {
+        TokenStandard::from(self.contract_type.as_str())
+    }
+}
+
 /// Represents an NFT lending transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct NftLending {
     /// The wallet address involved in the NFT lending transaction.
     pub wallet: String,
@@ -494,7 +1081,7 @@ pub struct NftLending {
 }
 
 /// Represents a bridge transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Bridge {
     /// The wallet address involved in the transaction.
     pub wallet: String,
@@ -544,8 +1131,15 @@ pub struct Bridge {
     pub r#type: String,
 }
 
+impl Bridge {
+    /// Returns the typed direction of the bridge transaction, parsed from `r#type`.
+    pub fn direction(&self) -> BridgeDirection {
+        BridgeDirection::from(self.r#type.as_str())
+    }
+}
+
 /// Represents a contract interaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ContractInteraction {
     /// The wallet address participating in the contract interaction.
     pub wallet: String,
@@ -574,7 +1168,7 @@ pub struct ContractInteraction {
 }
 
 /// Represents a wrap transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Wrap {
     /// The wallet address participating in the wrap transaction.
     pub wallet: String,
@@ -618,8 +1212,20 @@ pub struct Wrap {
     pub token_icon_link: String,
 }
 
+impl Wrap {
+    /// Returns the typed action of the wrap transaction, parsed from `action`.
+    pub fn wrap_action(&self) -> WrapAction {
+        WrapAction::from(self.action.as_str())
+    }
+
+    /// Returns the typed standard of the wrapped token, parsed from `token_type`.
+    pub fn token_standard(&self) -> TokenStandard {
+        TokenStandard::from(self.token_type.as_str())
+    }
+}
+
 /// Represents a Sudo Pool transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct SudoPool {
     /// The wallet address participating in the Sudo Pool transaction.
     pub wallet: String,
@@ -668,7 +1274,7 @@ pub struct SudoPool {
 }
 
 /// Represents a reward transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Reward {
     /// The wallet address participating in the reward transaction.
     pub wallet: String,
@@ -703,7 +1309,7 @@ pub struct Reward {
 }
 
 /// Represents a staking transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Staking {
     /// The wallet address participating in the staking transaction.
     pub wallet: String,
@@ -745,8 +1351,15 @@ pub struct Staking {
     pub action: String,
 }
 
+impl Staking {
+    /// Returns the typed action of the staking transaction, parsed from `action`.
+    pub fn stake_action(&self) -> StakeAction {
+        StakeAction::from(self.action.as_str())
+    }
+}
+
 /// Represents a Perpetual transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Perp {
     /// The wallet address participating in the Perpetual transaction.
     pub wallet: String,
@@ -832,8 +1445,26 @@ pub struct Perp {
     pub unrealized_pnl: Option<f64>,
 }
 
+impl Perp {
+    /// Returns the typed direction of the trade, parsed from `trade_direction`.
+    pub fn perp_direction(&self) -> PerpDirection {
+        PerpDirection::from(self.trade_direction.as_str())
+    }
+
+    /// Returns whether the trade is a long position. Unknown directions are not long.
+    pub fn is_long(&self) -> bool {
+        self.perp_direction() == PerpDirection::Long
+    }
+
+    /// Returns the realized plus the unrealized profit and loss, counting a missing unrealized
+    /// PnL as zero.
+    pub fn total_pnl(&self) -> f64 {
+        self.realized_pnl + self.unrealized_pnl.unwrap_or(0.0)
+    }
+}
+
 /// Represents a flashloan transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Flashloan {
     /// The wallet address participating in the flashloan transaction.
     pub wallet: String,
@@ -876,7 +1507,7 @@ pub struct Flashloan {
 }
 
 /// Represents a contract creation transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ContractCreation {
     /// The wallet address participating in the contract creation transaction.
     pub wallet: String,
@@ -905,7 +1536,7 @@ pub struct ContractCreation {
 }
 
 /// Represents an NFT liquidation transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct NftLiquidation {
     /// The wallet address participating in the NFT liquidation transaction.
     pub wallet: String,
@@ -952,7 +1583,7 @@ pub struct NftLiquidation {
 }
 
 /// Represents an option event transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct OptionType {
     /// The wallet address participating in the option event.
     pub wallet: String,
@@ -1000,8 +1631,20 @@ pub struct OptionType {
     pub r#type: String,
 }
 
+impl OptionType {
+    /// Returns the typed direction of the option, parsed from `direction`.
+    pub fn option_direction(&self) -> OptionDirection {
+        OptionDirection::from(self.direction.as_str())
+    }
+
+    /// Returns the typed status of the option position, parsed from `position_status`.
+    pub fn option_position_status(&self) -> OptionPositionStatus {
+        OptionPositionStatus::from(self.position_status.as_str())
+    }
+}
+
 /// Represents an NFT sweep transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct NftSweep {
     /// The wallet address involved in the NFT sweep transaction.
     pub wallet: String,
@@ -1045,7 +1688,8 @@ pub struct NftSweep {
     pub price: f64,
     /// The equivalent USD value of the NFT sweep.
     pub price_usd: f64,
-    /// The profit earned from the trade. This may be zero in some transactions.
+    /// The profit earned from the trade, denominated in the trade currency (see `currency_symbol`),
+    /// not in USD. This may be zero in some transactions. Use `profit_usd` for a USD value.
     pub profit: f64,
     /// The symbol of the currency used in the trade, such as WETH or ETH.
     pub currency_symbol: String,
@@ -1060,3 +1704,632 @@ pub struct NftSweep {
     /// Specifies if the transaction involved a bid being accepted.
     pub bid_accepted: bool,
 }
+
+impl NftSweep {
+    /// Returns the profit of the trade converted to USD.
+    ///
+    /// `profit` is denominated in the trade currency, so it is converted using the exchange rate
+    /// implied by `price_usd / price`. Returns `None` if `price` is zero, as the rate is unknown.
+    pub fn profit_usd(&self) -> Option<f64> {
+        if self.price == 0.0 {
+            return None;
+        }
+        Some(self.profit * self.price_usd / self.price)
+    }
+}
+
+/// Common details of the NFT involved in an NFT transaction, across the NFT variants.
+pub trait NftEvent {
+    /// Returns the contract address of the NFT collection.
+    fn nft_address(&self) -> &str;
+    /// Returns the name of the NFT.
+    fn nft_name(&self) -> &str;
+    /// Returns the token ID of the NFT within its collection.
+    fn nft_token_id(&self) -> &str;
+    /// Returns the full image URL of the NFT, if the API reports one for the variant.
+    fn image(&self) -> Option<&str>;
+    /// Returns the thumbnail image URL of the NFT, if the API reports one for the variant.
+    fn thumbnail(&self) -> Option<&str>;
+}
+
+/// Implements [`NftEvent`] for variants reporting images, given the field holding the address.
+macro_rules! impl_nft_event {
+    ($($ty:ty => $address:ident,)+) => {
+        $(
+            impl NftEvent for $ty {
+                fn nft_address(&self) -> &str {
+                    &self.$address
+                }
+
+                fn nft_name(&self) -> &str {
+                    &self.nft_name
+                }
+
+                fn nft_token_id(&self) -> &str {
+                    &self.nft_token_id
+                }
+
+                fn image(&self) -> Option<&str> {
+                    Some(&self.image)
+                }
+
+                fn thumbnail(&self) -> Option<&str> {
+                    Some(&self.thumbnail)
+                }
+            }
+        )+
+    };
+}
+
+impl_nft_event! {
+    NftMint => contract_address,
+    NftTrade => nft_address,
+    NftTransfer => contract_address,
+    NftLending => nft_address,
+    NftSweep => nft_address,
+}
+
+impl NftEvent for NftLiquidation {
+    fn nft_address(&self) -> &str {
+        &self.nft_address
+    }
+
+    fn nft_name(&self) -> &str {
+        &self.nft_name
+    }
+
+    fn nft_token_id(&self) -> &str {
+        &self.token_id
+    }
+
+    fn image(&self) -> Option<&str> {
+        None
+    }
+
+    fn thumbnail(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl Item {
+    /// Returns the NFT details of the item, if it is an NFT transaction.
+    pub fn as_nft_event(&self) -> Option<&dyn NftEvent> {
+        match self {
+            Item::NftMint(item) => Some(item),
+            Item::NftTrade(item) => Some(item),
+            Item::NftTransfer(item) => Some(item),
+            Item::NftLending(item) => Some(item),
+            Item::NftLiquidation(item) => Some(item),
+            Item::NftSweep(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl Item {
+    /// Returns the USD value of the item as a [`rust_decimal::Decimal`], if the API reports one.
+    ///
+    /// See [`Item::usd_value`] and [`to_decimal`].
+    pub fn usd_value_decimal(&self) -> Option<rust_decimal::Decimal> {
+        self.usd_value().and_then(to_decimal)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl Swap {
+    /// Returns the amount of the first token as a [`rust_decimal::Decimal`]. See [`to_decimal`].
+    pub fn token0_amount_decimal(&self) -> Option<rust_decimal::Decimal> {
+        to_decimal(self.token0_amount)
+    }
+
+    /// Returns the amount of the second token as a [`rust_decimal::Decimal`]. See [`to_decimal`].
+    pub fn token1_amount_decimal(&self) -> Option<rust_decimal::Decimal> {
+        to_decimal(self.token1_amount)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl Transfer {
+    /// Returns the USD value of the transfer as a [`rust_decimal::Decimal`]. See [`to_decimal`].
+    pub fn amount_usd_decimal(&self) -> Option<rust_decimal::Decimal> {
+        to_decimal(self.amount_usd)
+    }
+}
+
+/// Converts an amount to a [`rust_decimal::Decimal`] for exact accounting.
+///
+/// The amount is parsed from its shortest decimal representation, which is the number as written
+/// in the API response for values with up to 15 significant digits, rather than from its binary
+/// value. Sums of decimals are therefore free of the float artifacts that accumulate with `f64`
+/// (e.g. `0.1 + 0.2`). Digits beyond `f64` precision are already lost when the response is
+/// parsed; use [`DecimalItem`] to keep them. Returns `None` for non-finite amounts or amounts
+/// outside the range of `Decimal`.
+#[cfg(feature = "decimal")]
+pub fn to_decimal(amount: f64) -> Option<rust_decimal::Decimal> {
+    if !amount.is_finite() {
+        return None;
+    }
+    amount.to_string().parse().ok()
+}
+
+/// A feed item together with its numeric fields as exact decimals.
+///
+/// The decimals are parsed from the numbers as written in the JSON, rather than from the `f64`
+/// fields of the item, so amounts with more than 17 significant digits, common for token
+/// balances, are kept exactly, up to the 28 digits of `Decimal`. The item cannot be modified
+/// through the wrapper, so the decimals always match it.
+///
+/// See [`DecimalItem::from_json`], [`DecimalItem::from_feed_body`] and
+/// [`crate::CieloApi::get_feed_decimal`].
+#[cfg(feature = "decimal")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecimalItem {
+    /// The deserialized item.
+    item: Item,
+    /// The numeric fields of the item as written in the JSON, keyed by field name.
+    amounts: HashMap<String, rust_decimal::Decimal>,
+}
+
+#[cfg(feature = "decimal")]
+impl DecimalItem {
+    /// Parses a single feed item from its JSON text.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `serde_json::Error` if the text is not a valid feed item.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let item = serde_json::from_str(json)?;
+        let fields: HashMap<String, &serde_json::value::RawValue> = serde_json::from_str(json)?;
+        let amounts = fields
+            .into_iter()
+            .filter_map(|(field, value)| Some((field, parse_decimal(value.get())?)))
+            .collect();
+        Ok(Self { item, amounts })
+    }
+
+    /// Parses the items of a feed response body, as returned by
+    /// [`crate::CieloApi::get_feed_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `serde_json::Error` if the body is not a valid feed response.
+    pub fn from_feed_body(body: &[u8]) -> Result<Vec<Self>, serde_json::Error> {
+        /// The response envelope, with its data field left unparsed.
+        #[derive(Deserialize)]
+        struct RawResponse<'a> {
+            /// The data field, either an object with the items or the items themselves.
+            #[serde(borrow)]
+            data: &'a serde_json::value::RawValue,
+        }
+
+        /// The data field of a response in its object form.
+        #[derive(Deserialize)]
+        struct RawData<'a> {
+            /// The items of the page.
+            #[serde(borrow)]
+            items: Vec<&'a serde_json::value::RawValue>,
+        }
+
+        let response: RawResponse = serde_json::from_slice(body)?;
+        let data = response.data.get();
+        let items: Vec<&serde_json::value::RawValue> = if data.trim_start().starts_with('[') {
+            serde_json::from_str(data)?
+        } else {
+            serde_json::from_str::<RawData>(data)?.items
+        };
+        items
+            .into_iter()
+            .map(|item| Self::from_json(item.get()))
+            .collect()
+    }
+
+    /// Returns the item.
+    pub fn item(&self) -> &Item {
+        &self.item
+    }
+
+    /// Returns the item, discarding the decimals.
+    pub fn into_item(self) -> Item {
+        self.item
+    }
+
+    /// Returns the exact value of a numeric field of the item, by its name in the JSON, e.g.
+    /// `token0_amount`. Returns `None` if the field is missing, not a number, or outside the
+    /// range of `Decimal`.
+    pub fn amount(&self, field: &str) -> Option<rust_decimal::Decimal> {
+        self.amounts.get(field).copied()
+    }
+
+    /// Returns the exact USD value of the item, if the API reports one. See [`Item::usd_value`].
+    pub fn usd_value(&self) -> Option<rust_decimal::Decimal> {
+        match &self.item {
+            Item::Swap(_) | Item::SudoPool(_) => self.amount("token0_amount_usd"),
+            Item::Lp(_) => {
+                Some(self.amount("token0_amount_usd")? + self.amount("token1_amount_usd")?)
+            }
+            Item::NftMint(_) => self.amount("value_usd"),
+            Item::NftTrade(_)
+            | Item::NftLending(_)
+            | Item::NftLiquidation(_)
+            | Item::NftSweep(_) => self.amount("price_usd"),
+            Item::Transfer(_)
+            | Item::Lending(_)
+            | Item::Bridge(_)
+            | Item::Wrap(_)
+            | Item::Reward(_)
+            | Item::Staking(_)
+            | Item::Perp(_)
+            | Item::Flashloan(_)
+            | Item::ContractCreation(_) => self.amount("amount_usd"),
+            Item::NftTransfer(_) | Item::ContractInteraction(_) | Item::Option(_) => None,
+        }
+    }
+}
+
+/// Parses a JSON number as written into a `Decimal`, returning `None` for other JSON values.
+#[cfg(feature = "decimal")]
+fn parse_decimal(json: &str) -> Option<rust_decimal::Decimal> {
+    if !json.starts_with(|c: char| c == '-' || c.is_ascii_digit()) {
+        return None;
+    }
+    json.parse()
+        .or_else(|_| rust_decimal::Decimal::from_scientific(json))
+        .ok()
+}
+
+/// A flattened, uniform view of a feed item, e.g. for CSV or DataFrame export.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlatItem {
+    /// The wallet address the item belongs to.
+    pub wallet: String,
+    /// The readable label of the wallet.
+    pub wallet_label: String,
+    /// The hash of the transaction.
+    pub tx_hash: String,
+    /// The transaction type, derived from the item's variant.
+    pub tx_type: TxType,
+    /// The blockchain network the transaction occurred on.
+    pub chain: String,
+    /// The UNIX timestamp of the transaction.
+    pub timestamp: u64,
+    /// The number of the block the transaction is included in, if reported.
+    pub block: Option<u64>,
+    /// The USD value of the item, if reported. See [`Item::usd_value`].
+    pub usd_value: Option<f64>,
+    /// The counterparty of the wallet, if any. See [`Item::counterparty`].
+    pub counterparty: Option<String>,
+}
+
+impl From<&Item> for FlatItem {
+    fn from(item: &Item) -> Self {
+        Self {
+            wallet: item.wallet().to_string(),
+            wallet_label: item.wallet_label().to_string(),
+            tx_hash: item.tx_hash().to_string(),
+            tx_type: item.tx_type_enum(),
+            chain: item.chain().to_string(),
+            timestamp: item.timestamp(),
+            block: item.block(),
+            usd_value: item.usd_value(),
+            counterparty: item.counterparty().map(str::to_string),
+        }
+    }
+}
+
+impl Item {
+    /// Returns the item as a flat JSON object with the same keys for every variant.
+    ///
+    /// The object holds the fields of [`FlatItem`], with the transaction type under `type`, which
+    /// is convenient for consumers in other languages. Unlike serializing the item itself, the
+    /// variant-specific fields are left out.
+    pub fn to_normalized_json(&self) -> serde_json::Value {
+        let flat = FlatItem::from(self);
+        serde_json::json!({
+            "type": flat.tx_type,
+            "wallet": flat.wallet,
+            "wallet_label": flat.wallet_label,
+            "tx_hash": flat.tx_hash,
+            "chain": flat.chain,
+            "timestamp": flat.timestamp,
+            "block": flat.block,
+            "usd_value": flat.usd_value,
+            "counterparty": flat.counterparty,
+        })
+    }
+}
+
+/// Returns a name suitable for displaying an address in a UI.
+///
+/// This is the label (e.g. an ENS name) when it is not blank, and otherwise the address shortened
+/// to its first 6 and last 4 characters, e.g. `0x1234…abcd`. Short addresses are kept as is.
+pub fn display_name(label: &str, address: &str) -> String {
+    let label = label.trim();
+    if !label.is_empty() {
+        return label.to_string();
+    }
+
+    let chars: Vec<char> = address.chars().collect();
+    if chars.len() <= 12 {
+        return address.to_string();
+    }
+    let head: String = chars[..6].iter().collect();
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("{head}…{tail}")
+}
+
+/// Merges several feed results into one, sorted by timestamp with the newest first.
+///
+/// The sort is stable: items with equal timestamps keep the order of `pages`, then their order
+/// within each page. When `dedup` is set, only the first occurrence of each
+/// `(tx_hash, index)` pair is kept, which is useful when queries overlap.
+pub fn merge_sorted(pages: Vec<Vec<Item>>, dedup: bool) -> Vec<Item> {
+    let mut items: Vec<Item> = pages.into_iter().flatten().collect();
+    items.sort_by_key(|item| std::cmp::Reverse(item.timestamp()));
+
+    if dedup {
+        let mut seen = HashSet::new();
+        items.retain(|item| seen.insert((item.tx_hash().to_string(), item.index())));
+    }
+    items
+}
+
+/// Drops the items below a per-chain minimum USD value, preserving the order of the others.
+///
+/// `thresholds` maps chain names, as returned by [`Item::chain`], to their minimum USD value.
+/// Chains not listed use `default_min_usd`. Items without a USD value (see [`Item::usd_value`])
+/// are only kept when their chain's minimum is zero or less.
+pub fn filter_by_chain_min_usd(
+    items: Vec<Item>,
+    thresholds: &HashMap<String, f64>,
+    default_min_usd: f64,
+) -> Vec<Item> {
+    items
+        .into_iter()
+        .filter(|item| {
+            let min_usd = thresholds
+                .get(item.chain())
+                .copied()
+                .unwrap_or(default_min_usd);
+            match item.usd_value() {
+                Some(usd_value) => usd_value >= min_usd,
+                None => min_usd <= 0.0,
+            }
+        })
+        .collect()
+}
+
+/// Drops the transfers and NFT transfers internal to a user, preserving the order of the others.
+///
+/// A transfer is internal if it is sent to its own sender, or if both its sender and recipient
+/// are in `own_wallets`. EVM addresses are matched ignoring case. Items of other types are
+/// always kept.
+pub fn filter_self_transfers(items: Vec<Item>, own_wallets: &HashSet<String>) -> Vec<Item> {
+    let is_own = |address: &str| own_wallets.iter().any(|own| addresses_eq(own, address));
+
+    items
+        .into_iter()
+        .filter(|item| {
+            let (from, to) = match item {
+                Item::Transfer(item) => (&item.from, &item.to),
+                Item::NftTransfer(item) => (&item.from, &item.to),
+                _ => return true,
+            };
+            !(addresses_eq(from, to) || (is_own(from) && is_own(to)))
+        })
+        .collect()
+}
+
+/// Drops the swaps and transfers whose token market cap is below a minimum, preserving the order
+/// of the others.
+///
+/// Market caps are only reported when the feed is fetched with
+/// [`Filters::include_market_cap`](crate::api::feed::Filters::include_market_cap) set. Swaps and
+/// transfers without a market cap, including those whose [`TokenMarketCap::market_cap`] is
+/// `null`, are kept only if `keep_missing` is true. Items of other types are always kept.
+pub fn filter_by_market_cap(
+    items: Vec<Item>,
+    min_market_cap: f64,
+    keep_missing: bool,
+) -> Vec<Item> {
+    items
+        .into_iter()
+        .filter(|item| {
+            let token_market_cap = match item {
+                Item::Swap(item) => &item.token_market_cap,
+                Item::Transfer(item) => &item.token_market_cap,
+                _ => return true,
+            };
+            match token_market_cap
+                .as_ref()
+                .and_then(|token_market_cap| token_market_cap.market_cap)
+            {
+                Some(market_cap) => market_cap >= min_market_cap,
+                None => keep_missing,
+            }
+        })
+        .collect()
+}
+
+/// Returns the swaps among the items, preserving their order.
+pub fn only_swaps(items: &[Item]) -> Vec<&Swap> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Swap(swap) => Some(swap),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the transfers among the items, preserving their order.
+pub fn only_transfers(items: &[Item]) -> Vec<&Transfer> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Transfer(transfer) => Some(transfer),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the NFT trades among the items, preserving their order.
+pub fn only_nft_trades(items: &[Item]) -> Vec<&NftTrade> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            Item::NftTrade(trade) => Some(trade),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the liquidity pool transactions among the items, preserving their order.
+pub fn only_lps(items: &[Item]) -> Vec<&Lp> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Lp(lp) => Some(lp),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the transfers received by the given wallet, i.e. those where it is the `to` address.
+///
+/// Only [`Item::Transfer`] items are considered. EVM addresses are compared case-insensitively.
+pub fn inflows(items: &[Item], wallet: &str) -> Vec<Transfer> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Transfer(transfer) if addresses_eq(&transfer.to, wallet) => {
+                Some(transfer.clone())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the transfers sent by the given wallet, i.e. those where it is the `from` address.
+///
+/// Only [`Item::Transfer`] items are considered. EVM addresses are compared case-insensitively.
+pub fn outflows(items: &[Item], wallet: &str) -> Vec<Transfer> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Transfer(transfer) if addresses_eq(&transfer.from, wallet) => {
+                Some(transfer.clone())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the mean USD value of the items of each transaction type.
+///
+/// Items without a USD value (see [`Item::usd_value`]) are skipped, so types with no valued
+/// items are absent from the result.
+pub fn avg_value_by_type(items: &[Item]) -> HashMap<TxType, f64> {
+    let mut totals: HashMap<TxType, (f64, u32)> = HashMap::new();
+    for item in items {
+        if let Some(value) = item.usd_value() {
+            let (sum, count) = totals.entry(item.tx_type_enum()).or_default();
+            *sum += value;
+            *count += 1;
+        }
+    }
+
+    totals
+        .into_iter()
+        .map(|(tx_type, (sum, count))| (tx_type, sum / f64::from(count)))
+        .collect()
+}
+
+/// Returns the number of items of each transaction type, e.g. to summarize a page of activity.
+///
+/// Types without items are absent from the result.
+pub fn count_by_tx_type(items: &[Item]) -> HashMap<TxType, usize> {
+    let mut counts = HashMap::new();
+    for item in items {
+        *counts.entry(item.tx_type_enum()).or_default() += 1;
+    }
+    counts
+}
+
+/// Groups items by transaction hash, e.g. to reconstruct a transaction that emitted both a swap
+/// and a transfer.
+///
+/// Items within a group are ordered by their [`Item::index`] in the transaction.
+pub fn group_by_tx_hash(items: Vec<Item>) -> HashMap<String, Vec<Item>> {
+    let mut groups: HashMap<String, Vec<Item>> = HashMap::new();
+    for item in items {
+        groups
+            .entry(item.tx_hash().to_string())
+            .or_default()
+            .push(item);
+    }
+    for group in groups.values_mut() {
+        group.sort_by_key(Item::index);
+    }
+    groups
+}
+
+/// Returns the net amount of each token swapped by each wallet, keyed by `(wallet, token_address)`.
+///
+/// Only [`Item::Swap`] items are considered. The sign convention follows the wallet's balance:
+/// `token0` is the token sold, so its amount is subtracted, and `token1` is the token bought, so
+/// its amount is added. A positive result means the wallet accumulated the token overall.
+pub fn net_token_flow(items: &[Item]) -> HashMap<(String, String), f64> {
+    let mut flows = HashMap::new();
+    for item in items {
+        if let Item::Swap(swap) = item {
+            *flows
+                .entry((swap.wallet.clone(), swap.token0_address.clone()))
+                .or_default() -= swap.token0_amount;
+            *flows
+                .entry((swap.wallet.clone(), swap.token1_address.clone()))
+                .or_default() += swap.token1_amount;
+        }
+    }
+    flows
+}
+
+/// Returns the distinct `(chain, token_address)` pairs of the tokens with the given symbol.
+///
+/// Swaps and transfers are scanned, and pairs are returned in the order they are first seen.
+/// Symbols are compared ignoring ASCII case. Many tokens share a symbol, so this helps choosing
+/// the address to filter the feed by instead of the ambiguous symbol.
+pub fn addresses_for_symbol(items: &[Item], symbol: &str) -> Vec<(String, String)> {
+    let mut pairs: Vec<(String, String)> = Vec::new();
+    for item in items {
+        let tokens = match item {
+            Item::Swap(swap) => vec![
+                (&swap.token0_symbol, &swap.token0_address),
+                (&swap.token1_symbol, &swap.token1_address),
+            ],
+            Item::Transfer(transfer) => vec![(&transfer.symbol, &transfer.contract_address)],
+            _ => continue,
+        };
+
+        for (token_symbol, address) in tokens {
+            let seen = pairs
+                .iter()
+                .any(|(chain, seen)| chain == item.chain() && addresses_eq(seen, address));
+            if token_symbol.eq_ignore_ascii_case(symbol) && !seen {
+                pairs.push((item.chain().to_string(), address.clone()));
+            }
+        }
+    }
+    pairs
+}
+
+/// Compares two addresses, ignoring case for EVM (`0x`-prefixed) addresses.
+///
+/// Other addresses, such as Solana's base58 ones, are case-sensitive and compared exactly.
+pub(crate) fn addresses_eq(a: &str, b: &str) -> bool {
+    if a.starts_with("0x") && b.starts_with("0x") {
+        a.eq_ignore_ascii_case(b)
+    } else {
+        a == b
+    }
+}
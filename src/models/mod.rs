@@ -3,6 +3,7 @@
 //! This module defines the core data structures used for handling API responses
 //! and pagination in the application.
 
+pub mod balances;
 pub mod feed;
 
 use serde::{Deserialize, Serialize};
@@ -22,12 +23,75 @@ pub struct Response<T> {
     pub message: Option<String>,
 }
 
+impl<T> Response<T> {
+    /// Creates a successful response without items or further pages, standing in for an empty
+    /// body.
+    pub(crate) fn empty() -> Self {
+        Self {
+            status: "ok".to_string(),
+            data: ResponseData {
+                items: Vec::new(),
+                paging: Paging {
+                    total_rows_in_page: 0,
+                    has_next_page: false,
+                    next_object: None,
+                },
+            },
+            message: None,
+        }
+    }
+
+    /// Checks that the API reported the response as successful, i.e. its status is `ok`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns [`crate::Error::Api`] with the message of the response, or its
+    /// status if there is no message.
+    pub fn check_status(&self) -> Result<(), crate::Error> {
+        if self.status.eq_ignore_ascii_case("ok") {
+            return Ok(());
+        }
+        Err(crate::Error::Api(
+            self.message.clone().unwrap_or_else(|| self.status.clone()),
+        ))
+    }
+
+    /// Checks the paging invariants of the response.
+    ///
+    /// The number of items must match `total_rows_in_page`, and `next_object` must be set when
+    /// `has_next_page` is true.
+    ///
+    /// # Errors
+    ///
+    /// This function returns [`crate::Error::InvalidResponse`] describing the first violation.
+    pub fn validate(&self) -> Result<(), crate::Error> {
+        let paging = &self.data.paging;
+        if paging.total_rows_in_page != self.data.items.len() as u64 {
+            return Err(crate::Error::InvalidResponse(format!(
+                "total_rows_in_page is {} but {} items were returned",
+                paging.total_rows_in_page,
+                self.data.items.len()
+            )));
+        }
+        if paging.has_next_page && paging.next_object.is_none() {
+            return Err(crate::Error::InvalidResponse(
+                "has_next_page is true but next_object is missing".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
 /// A struct representing the data field in the API response.
 ///
+/// Besides the `{items, paging}` object, a bare array of items is accepted, as returned by some
+/// endpoints. Missing paging information describes a single, complete page.
+///
 /// # Type Parameters
 ///
 /// * `T` - The type of the items field.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(from = "ResponseDataRepr<T>")]
 pub struct ResponseData<T> {
     /// A vector of items of type `T`.
     pub items: Vec<T>,
@@ -35,6 +99,37 @@ pub struct ResponseData<T> {
     pub paging: Paging,
 }
 
+/// The shapes the data field of an API response may take.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ResponseDataRepr<T> {
+    /// An object holding the items and, usually, the paging information.
+    Object {
+        /// The items of the page.
+        items: Vec<T>,
+        /// The paging information, if reported.
+        paging: Option<Paging>,
+    },
+    /// A bare array of items without paging information.
+    Array(Vec<T>),
+}
+
+impl<T> From<ResponseDataRepr<T>> for ResponseData<T> {
+    fn from(repr: ResponseDataRepr<T>) -> Self {
+        let (items, paging) = match repr {
+            ResponseDataRepr::Object { items, paging } => (items, paging),
+            ResponseDataRepr::Array(items) => (items, None),
+        };
+        let total_rows_in_page = items.len() as u64;
+        let paging = paging.unwrap_or(Paging {
+            total_rows_in_page,
+            has_next_page: false,
+            next_object: None,
+        });
+        Self { items, paging }
+    }
+}
+
 /// A struct representing the paging information in the API response.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Paging {
@@ -1,11 +1,26 @@
 //!This module provides extensions for the reqwest library, including retry strategies.
 
-use std::time::Duration;
+use std::{
+    io::ErrorKind,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering},
+        Arc, Mutex, RwLock,
+    },
+    time::{Duration, SystemTime},
+};
 
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use http::Extensions;
+use reqwest::{header::HeaderValue, Request, Response, StatusCode, Url};
+use reqwest_middleware::{Middleware, Next};
 use reqwest_retry::{
     policies::{ExponentialBackoff, ExponentialBackoffBuilder},
-    RetryTransientMiddleware, Retryable, RetryableStrategy,
+    RetryDecision, RetryPolicy, RetryTransientMiddleware, Retryable, RetryableStrategy,
 };
+use serde::de::DeserializeOwned;
+
+use crate::time::Instant;
 
 /// Creates a retry strategy using exponential backoff.
 ///
@@ -14,15 +29,17 @@ use reqwest_retry::{
 /// * `min_retry_interval` - Minimum retry interval in milliseconds.
 /// * `max_retry_interval` - Maximum retry interval in milliseconds.
 /// * `max_retries` - Maximum number of retries.
+/// * `total_retry_deadline` - Optional maximum time since the first attempt after which no retry is scheduled.
 ///
 /// # Returns
 ///
-/// * `RetryTransientMiddleware<ExponentialBackoff, Retry>` - A middleware configured with the retry policy and strategy.
+/// * `RetryTransientMiddleware<DeadlinePolicy, Retry>` - A middleware configured with the retry policy and strategy.
 pub fn get_retry_strategy(
     min_retry_interval: u64,
     max_retry_interval: u64,
     max_retries: u32,
-) -> RetryTransientMiddleware<ExponentialBackoff, Retry> {
+    total_retry_deadline: Option<Duration>,
+) -> RetryTransientMiddleware<DeadlinePolicy, Retry> {
     let retry_policy = ExponentialBackoffBuilder::default()
         .retry_bounds(
             Duration::from_millis(min_retry_interval),
@@ -30,7 +47,36 @@ pub fn get_retry_strategy(
         )
         .build_with_max_retries(max_retries);
 
-    RetryTransientMiddleware::new_with_policy_and_strategy(retry_policy, Retry)
+    RetryTransientMiddleware::new_with_policy_and_strategy(
+        DeadlinePolicy {
+            inner: retry_policy,
+            deadline: total_retry_deadline,
+        },
+        Retry,
+    )
+}
+
+/// A retry policy bounding the total time spent retrying on top of an exponential backoff.
+pub struct DeadlinePolicy {
+    /// The policy deciding on retries within the deadline.
+    inner: ExponentialBackoff,
+    /// The maximum time since the first attempt after which no retry is scheduled.
+    deadline: Option<Duration>,
+}
+
+impl RetryPolicy for DeadlinePolicy {
+    /// Defers to the exponential backoff, unless the next retry would start past the deadline.
+    fn should_retry(&self, request_start_time: SystemTime, n_past_retries: u32) -> RetryDecision {
+        let decision = self.inner.should_retry(request_start_time, n_past_retries);
+        match (decision, self.deadline) {
+            (RetryDecision::Retry { execute_after }, Some(deadline))
+                if execute_after > request_start_time + deadline =>
+            {
+                RetryDecision::DoNotRetry
+            }
+            (decision, _) => decision,
+        }
+    }
 }
 
 /// A struct implementing the `RetryableStrategy` trait for handling retry logic.
@@ -52,13 +98,696 @@ impl RetryableStrategy for Retry {
     ) -> Option<Retryable> {
         match res {
             Ok(success) => {
-                if success.status() != 200 {
+                if !success.status().is_success() {
                     Some(Retryable::Transient)
                 } else {
                     None
                 }
             }
-            Err(error) => reqwest_retry::default_on_request_failure(error),
+            Err(error) => Some(classify_error(error)),
+        }
+    }
+}
+
+/// Classifies an attempt that failed without a response.
+///
+/// Errors raised by another middleware are fatal, as retrying cannot fix them. Errors raised by
+/// reqwest are classified by [`classify_reqwest_error`].
+fn classify_error(error: &reqwest_middleware::Error) -> Retryable {
+    match error {
+        reqwest_middleware::Error::Reqwest(error) => classify_reqwest_error(error),
+        reqwest_middleware::Error::Middleware(_) => Retryable::Fatal,
+    }
+}
+
+/// Classifies a reqwest error raised without a response.
+///
+/// Timeouts and connection failures, which include refused connections, DNS resolution failures
+/// and failed TLS handshakes, are transient, as are errors caused by a connection that was reset,
+/// aborted or closed mid-request. Anything else, such as errors building the request or
+/// decoding the response, is fatal, as retrying cannot fix it.
+pub(crate) fn classify_reqwest_error(error: &reqwest::Error) -> Retryable {
+    if error.is_builder() {
+        return Retryable::Fatal;
+    }
+    if error.is_timeout() || is_connect(error) || is_connection_lost(error) {
+        return Retryable::Transient;
+    }
+    Retryable::Fatal
+}
+
+/// Returns `true` if the error occurred while connecting.
+///
+/// `reqwest::Error::is_connect` is not available on wasm, where connection failures surface as
+/// other errors.
+#[cfg(not(target_arch = "wasm32"))]
+fn is_connect(error: &reqwest::Error) -> bool {
+    error.is_connect()
+}
+
+/// Returns `true` if the error occurred while connecting, which cannot be told apart on wasm.
+#[cfg(target_arch = "wasm32")]
+fn is_connect(_error: &reqwest::Error) -> bool {
+    false
+}
+
+/// Returns `true` if the error was caused by an I/O error signalling a lost connection, or by
+/// the connection being closed before the whole response was received.
+fn is_connection_lost(error: &reqwest::Error) -> bool {
+    let mut source = std::error::Error::source(error);
+    while let Some(error) = source {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(hyper_error) = error.downcast_ref::<hyper::Error>() {
+            if hyper_error.is_incomplete_message() || hyper_error.is_canceled() {
+                return true;
+            }
+        }
+        if let Some(io_error) = error.downcast_ref::<std::io::Error>() {
+            return matches!(
+                io_error.kind(),
+                ErrorKind::ConnectionRefused
+                    | ErrorKind::ConnectionReset
+                    | ErrorKind::ConnectionAborted
+                    | ErrorKind::NotConnected
+                    | ErrorKind::BrokenPipe
+                    | ErrorKind::TimedOut
+                    | ErrorKind::UnexpectedEof
+            );
+        }
+        source = error.source();
+    }
+    false
+}
+
+/// A single attempt made while sending a request, including retries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryAttempt {
+    /// The HTTP status code of the attempt, or `None` if no response was received.
+    pub status: Option<u16>,
+    /// The time elapsed between sending the first attempt and the end of this one.
+    pub elapsed: Duration,
+}
+
+/// Per-request log of attempts, attached to a request through its extensions.
+///
+/// The retry middleware passes the same extensions to every attempt of a request,
+/// so a single log collects the whole retry sequence.
+#[derive(Debug, Clone)]
+pub struct AttemptLog {
+    /// The instant the log was created, used as the reference for elapsed times.
+    start: Instant,
+    /// The attempts recorded so far.
+    attempts: Arc<Mutex<Vec<RetryAttempt>>>,
+}
+
+impl AttemptLog {
+    /// Creates an empty attempt log starting now.
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            attempts: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Records an attempt with the given status code and returns the number of attempts so far.
+    fn record(&self, status: Option<u16>) -> u32 {
+        let attempt = RetryAttempt {
+            status,
+            elapsed: self.start.elapsed(),
+        };
+        let mut attempts = self
+            .attempts
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        attempts.push(attempt);
+        attempts.len() as u32
+    }
+
+    /// Returns a copy of the attempts recorded so far.
+    pub fn attempts(&self) -> Vec<RetryAttempt> {
+        self.attempts
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+}
+
+/// Context passed to the retry callback whenever an attempt is classified as retryable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryContext {
+    /// The 1-based number of the attempt that failed.
+    pub attempt: u32,
+    /// The HTTP status code of the failed attempt, or `None` if no response was received.
+    pub status: Option<u16>,
+}
+
+/// Callback invoked whenever an attempt is classified as retryable.
+pub type RetryCallback = Arc<dyn Fn(&RetryContext) + Send + Sync>;
+
+/// A snapshot of the request counters of a client, for quota planning.
+///
+/// Every HTTP attempt counts as a request, including retries, so the counters reflect the
+/// quota actually used.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ApiStats {
+    /// The number of requests sent, including retries.
+    pub requests: u64,
+    /// The number of requests answered with a successful status.
+    pub successes: u64,
+    /// The number of requests that were retries of a failed one.
+    pub retries: u64,
+    /// The number of requests that failed, with an unsuccessful status or without a response.
+    pub errors: u64,
+}
+
+/// The request counters of a client, shared between its clones.
+#[derive(Debug, Default)]
+pub struct StatsCounters {
+    /// The number of requests sent, including retries.
+    requests: AtomicU64,
+    /// The number of requests answered with a successful status.
+    successes: AtomicU64,
+    /// The number of requests that were retries of a failed one.
+    retries: AtomicU64,
+    /// The number of requests that failed.
+    errors: AtomicU64,
+}
+
+impl StatsCounters {
+    /// Counts an attempt, given its 1-based number and whether it succeeded.
+    fn record(&self, attempt: u32, success: bool) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        if attempt > 1 {
+            self.retries.fetch_add(1, Ordering::Relaxed);
+        }
+        let outcome = if success {
+            &self.successes
+        } else {
+            &self.errors
+        };
+        outcome.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the current values of the counters.
+    pub fn snapshot(&self) -> ApiStats {
+        ApiStats {
+            requests: self.requests.load(Ordering::Relaxed),
+            successes: self.successes.load(Ordering::Relaxed),
+            retries: self.retries.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Middleware observing every attempt of a request.
+///
+/// It records each attempt into the request's [`AttemptLog`], attaching one if missing, counts
+/// it in the client's [`StatsCounters`], and reports attempts classified as retryable by
+/// [`Retry`] to the optional callback and, with the `tracing` feature, as a `tracing` event.
+///
+/// It must be registered after the retry middleware so that it runs once per attempt.
+pub struct AttemptRecorder {
+    /// The callback invoked on retryable attempts.
+    on_retry: Option<RetryCallback>,
+    /// The counters of the client.
+    stats: Arc<StatsCounters>,
+}
+
+impl AttemptRecorder {
+    /// Creates a new recorder with an optional retry callback, counting into `stats`.
+    pub fn new(on_retry: Option<RetryCallback>, stats: Arc<StatsCounters>) -> Self {
+        Self { on_retry, stats }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Middleware for AttemptRecorder {
+    /// Runs the next middleware and records the outcome of the attempt.
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let log = match extensions.get::<AttemptLog>() {
+            Some(log) => log.clone(),
+            None => {
+                let log = AttemptLog::new();
+                extensions.insert(log.clone());
+                log
+            }
+        };
+
+        let res = next.run(req, extensions).await;
+
+        let status = res.as_ref().ok().map(|response| response.status().as_u16());
+        let attempt = log.record(status);
+        self.stats.record(
+            attempt,
+            res.as_ref()
+                .is_ok_and(|response| response.status().is_success()),
+        );
+
+        if matches!(Retry.handle(&res), Some(Retryable::Transient)) {
+            let context = RetryContext { attempt, status };
+
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                attempt = context.attempt,
+                status = ?context.status,
+                "Retryable request attempt failed"
+            );
+
+            if let Some(on_retry) = &self.on_retry {
+                on_retry(&context);
+            }
+        }
+
+        res
+    }
+}
+
+/// A middleware delaying requests to stay within a rate limit.
+///
+/// The limiter is a token bucket refilled at the configured rate, holding a single token so
+/// that requests are evenly spaced. Requests wait for a token rather than failing.
+///
+/// It must be registered after the retry middleware so that retries are limited too.
+#[cfg(feature = "rate-limit")]
+pub struct RateLimit {
+    /// The limiter shared by every request of the client.
+    limiter: governor::DefaultDirectRateLimiter,
+}
+
+#[cfg(feature = "rate-limit")]
+impl RateLimit {
+    /// Creates a new middleware allowing the given number of requests per second.
+    pub fn new(requests_per_second: std::num::NonZeroU32) -> Self {
+        let quota =
+            governor::Quota::per_second(requests_per_second).allow_burst(std::num::NonZeroU32::MIN);
+        Self {
+            limiter: governor::RateLimiter::direct(quota),
         }
     }
 }
+
+#[cfg(feature = "rate-limit")]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Middleware for RateLimit {
+    /// Waits until the rate limit allows another request, then runs the next middleware.
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        self.limiter.until_ready().await;
+        next.run(req, extensions).await
+    }
+}
+
+/// The error returned by [`CircuitBreaker`] while open, converted to
+/// [`crate::Error::CircuitOpen`].
+#[derive(Debug)]
+pub struct CircuitOpenError;
+
+impl std::fmt::Display for CircuitOpenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("circuit breaker open")
+    }
+}
+
+impl std::error::Error for CircuitOpenError {}
+
+/// A middleware that stops sending requests after repeated failures.
+///
+/// Server errors (`5xx`), `429 Too Many Requests` and transport failures classified as transient
+/// count as failures; any other outcome, such as `400 Bad Request` or `401 Unauthorized`, shows
+/// that the API is up and resets the count. Once `failure_threshold` consecutive requests have
+/// failed, the breaker opens and requests fail immediately with [`CircuitOpenError`]. After
+/// `cooldown`, it is half-open: a single probe request is sent, while concurrent requests keep
+/// failing fast, and its outcome either closes the breaker or opens it for another cooldown.
+///
+/// It must be registered before the retry middleware so that a retried request counts once
+/// and is not retried while the breaker is open.
+pub struct CircuitBreaker {
+    /// The number of consecutive failures opening the breaker.
+    failure_threshold: u32,
+    /// How long the breaker stays open before letting requests through again.
+    cooldown: Duration,
+    /// The number of consecutive failed requests.
+    failures: AtomicU32,
+    /// When the breaker last opened, if it is open or half-open.
+    opened_at: Mutex<Option<Instant>>,
+    /// Whether a probe request is in flight while half-open.
+    probing: AtomicBool,
+}
+
+impl CircuitBreaker {
+    /// Creates a new closed breaker. A threshold of 0 is treated as 1.
+    pub fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            cooldown,
+            failures: AtomicU32::new(0),
+            opened_at: Mutex::new(None),
+            probing: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns `true` if the outcome suggests the API is down or overloaded.
+    fn is_failure(res: &reqwest_middleware::Result<Response>) -> bool {
+        match res {
+            Ok(response) => {
+                response.status().is_server_error()
+                    || response.status() == StatusCode::TOO_MANY_REQUESTS
+            }
+            Err(error) => matches!(classify_error(error), Retryable::Transient),
+        }
+    }
+}
+
+/// Clears the probe flag of a [`CircuitBreaker`] when dropped, including when the probe request
+/// is cancelled.
+struct ProbeGuard<'a>(&'a AtomicBool);
+
+impl Drop for ProbeGuard<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Middleware for CircuitBreaker {
+    /// Fails fast while the breaker is open, and otherwise runs the next middleware and records
+    /// the outcome.
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let opened_at = *self
+            .opened_at
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let _probe = match opened_at {
+            Some(opened_at) if opened_at.elapsed() < self.cooldown => {
+                return Err(reqwest_middleware::Error::middleware(CircuitOpenError));
+            }
+            // Half-open: only the request winning the flag is sent
+            Some(_) => {
+                if self.probing.swap(true, Ordering::AcqRel) {
+                    return Err(reqwest_middleware::Error::middleware(CircuitOpenError));
+                }
+                Some(ProbeGuard(&self.probing))
+            }
+            None => None,
+        };
+
+        let res = next.run(req, extensions).await;
+
+        let mut opened_at = self
+            .opened_at
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if Self::is_failure(&res) {
+            let failures = self.failures.fetch_add(1, Ordering::Relaxed) + 1;
+            if failures >= self.failure_threshold {
+                *opened_at = Some(Instant::now());
+            }
+        } else {
+            self.failures.store(0, Ordering::Relaxed);
+            *opened_at = None;
+        }
+
+        res
+    }
+}
+
+/// The name of the header carrying the API key.
+pub const API_KEY_HEADER: &str = "X-API-KEY";
+
+/// Asynchronous provider of a fresh API key, invoked when the current key is rejected.
+pub type KeyProvider =
+    Arc<dyn Fn() -> BoxFuture<'static, Result<String, crate::Error>> + Send + Sync>;
+
+/// Creates a sensitive header value from an API key, trimming surrounding whitespace.
+///
+/// # Errors
+///
+/// This function returns [`crate::Error::InvalidApiKey`] if the API key is empty or contains
+/// characters not allowed in a header.
+pub fn api_key_header(api_key: &str) -> Result<HeaderValue, crate::Error> {
+    let api_key = api_key.trim();
+    if api_key.is_empty() {
+        return Err(crate::Error::InvalidApiKey(
+            "the API key is empty".to_string(),
+        ));
+    }
+
+    let mut value = HeaderValue::from_str(api_key).map_err(|_| {
+        crate::Error::InvalidApiKey("the API key contains invalid characters".to_string())
+    })?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+/// Middleware setting the API key header on every attempt.
+///
+/// When a [`KeyProvider`] is configured and an attempt is rejected with `401 Unauthorized`, a
+/// fresh key is requested from the provider, stored for subsequent requests, and the attempt is
+/// sent once more with it. Concurrent requests rejected at the same time may each invoke the
+/// provider.
+pub struct ApiKeyAuth {
+    /// The API key currently in use.
+    api_key: RwLock<HeaderValue>,
+    /// The provider of fresh API keys.
+    key_provider: Option<KeyProvider>,
+}
+
+impl ApiKeyAuth {
+    /// Creates a new middleware with the initial API key and an optional key provider.
+    pub fn new(api_key: HeaderValue, key_provider: Option<KeyProvider>) -> Self {
+        Self {
+            api_key: RwLock::new(api_key),
+            key_provider,
+        }
+    }
+
+    /// Sets the current API key header on the request.
+    fn authorize(&self, mut req: Request) -> Request {
+        let api_key = self
+            .api_key
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone();
+        req.headers_mut().insert(API_KEY_HEADER, api_key);
+        req
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Middleware for ApiKeyAuth {
+    /// Sends the request with the current API key, refreshing it once on `401 Unauthorized`.
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let retry_req = match self.key_provider {
+            Some(_) => req.try_clone(),
+            None => None,
+        };
+
+        let res = next.clone().run(self.authorize(req), extensions).await;
+
+        let (Some(key_provider), Some(retry_req)) = (&self.key_provider, retry_req) else {
+            return res;
+        };
+        if !matches!(&res, Ok(response) if response.status() == StatusCode::UNAUTHORIZED) {
+            return res;
+        }
+
+        let api_key = key_provider()
+            .await
+            .and_then(|api_key| api_key_header(&api_key))
+            .map_err(reqwest_middleware::Error::middleware)?;
+        *self
+            .api_key
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = api_key;
+
+        next.run(self.authorize(retry_req), extensions).await
+    }
+}
+
+/// Converts an unsuccessful response into an error carrying its body.
+///
+/// `401 Unauthorized` and `403 Forbidden` map to [`crate::Error::Unauthorized`], any other
+/// status to [`crate::Error::StatusNot200`], both with a context carrying the URL of the request
+/// as returned by [`redacted_url`]. If the body cannot be read, that error is returned instead.
+pub async fn status_error(response: Response, max_bytes: Option<usize>) -> crate::Error {
+    let status = response.status();
+    let url = redacted_url(response.url());
+    let body = match read_text(response, max_bytes).await {
+        Ok(body) => body,
+        Err(error) => return error,
+    };
+
+    if status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN {
+        crate::Error::Unauthorized {
+            body,
+            context: crate::ErrorContext {
+                url: Some(url),
+                ..Default::default()
+            },
+        }
+    } else {
+        crate::Error::StatusNot200 {
+            status: status.as_u16(),
+            body,
+            context: crate::ErrorContext {
+                url: Some(url),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Query parameter names whose values are never written to errors or traces.
+const SENSITIVE_PARAMS: [&str; 5] = ["key", "api_key", "apikey", "x-api-key", "secret"];
+
+/// Returns the URL with sensitive query parameter values redacted.
+///
+/// The API key is sent as a header and never appears in the URL, but parameters that may carry
+/// secrets are redacted anyway in case a caller passes one.
+pub fn redacted_url(url: &Url) -> String {
+    let mut url = url.clone();
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(name, value)| {
+            let value = if SENSITIVE_PARAMS.contains(&name.to_ascii_lowercase().as_str()) {
+                "REDACTED".to_string()
+            } else {
+                value.into_owned()
+            };
+            (name.into_owned(), value)
+        })
+        .collect();
+    if !pairs.is_empty() {
+        url.query_pairs_mut().clear().extend_pairs(&pairs);
+    }
+    url.to_string()
+}
+
+/// Reads a response body as text, enforcing an optional maximum size.
+///
+/// # Errors
+///
+/// This function returns [`crate::Error::ResponseTooLarge`] if the body exceeds `max_bytes`, or
+/// another `crate::Error` if it cannot be read.
+pub async fn read_text(
+    response: Response,
+    max_bytes: Option<usize>,
+) -> Result<String, crate::Error> {
+    match max_bytes {
+        Some(max_bytes) => {
+            let body = read_limited(response, max_bytes).await?;
+            Ok(String::from_utf8_lossy(&body).into_owned())
+        }
+        None => Ok(response.text().await?),
+    }
+}
+
+/// Reads and deserializes a JSON response body, enforcing an optional maximum size.
+///
+/// Returns `None` if the body is empty, e.g. for `204 No Content` responses, so that the absence
+/// of data can be told apart from malformed data.
+///
+/// # Errors
+///
+/// This function returns [`crate::Error::ResponseTooLarge`] if the body exceeds `max_bytes`, or
+/// another `crate::Error` if it cannot be read or deserialized.
+pub async fn read_json<T: DeserializeOwned>(
+    response: Response,
+    max_bytes: Option<usize>,
+) -> Result<Option<T>, crate::Error> {
+    let body = read_bytes(response, max_bytes).await?;
+
+    if body.iter().all(u8::is_ascii_whitespace) {
+        return Ok(None);
+    }
+    Ok(Some(serde_json::from_slice(&body)?))
+}
+
+/// Reads a raw response body, enforcing an optional maximum size.
+///
+/// # Errors
+///
+/// This function returns [`crate::Error::ResponseTooLarge`] if the body exceeds `max_bytes`, or
+/// another `crate::Error` if it cannot be read.
+pub async fn read_bytes(
+    response: Response,
+    max_bytes: Option<usize>,
+) -> Result<Bytes, crate::Error> {
+    match max_bytes {
+        Some(max_bytes) => Ok(read_limited(response, max_bytes).await?.into()),
+        None => Ok(response.bytes().await?),
+    }
+}
+
+/// Reads a response body chunk by chunk, failing as soon as it exceeds `max_bytes`.
+///
+/// # Errors
+///
+/// This function returns [`crate::Error::ResponseTooLarge`] if the body exceeds `max_bytes`, or
+/// another `crate::Error` if it cannot be read.
+#[cfg(not(target_arch = "wasm32"))]
+async fn read_limited(mut response: Response, max_bytes: usize) -> Result<Vec<u8>, crate::Error> {
+    if response
+        .content_length()
+        .is_some_and(|length| length > max_bytes as u64)
+    {
+        return Err(crate::Error::ResponseTooLarge(max_bytes));
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if body.len() + chunk.len() > max_bytes {
+            return Err(crate::Error::ResponseTooLarge(max_bytes));
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body)
+}
+
+/// Reads a response body, failing if it exceeds `max_bytes`.
+///
+/// The browser fetch backend used on wasm32 cannot read a body incrementally, so only a declared
+/// `Content-Length` stops the read early; otherwise the body is checked once buffered.
+///
+/// # Errors
+///
+/// This function returns [`crate::Error::ResponseTooLarge`] if the body exceeds `max_bytes`, or
+/// another `crate::Error` if it cannot be read.
+#[cfg(target_arch = "wasm32")]
+async fn read_limited(response: Response, max_bytes: usize) -> Result<Vec<u8>, crate::Error> {
+    if response
+        .content_length()
+        .is_some_and(|length| length > max_bytes as u64)
+    {
+        return Err(crate::Error::ResponseTooLarge(max_bytes));
+    }
+
+    let body = response.bytes().await?;
+    if body.len() > max_bytes {
+        return Err(crate::Error::ResponseTooLarge(max_bytes));
+    }
+    Ok(body.to_vec())
+}
@@ -0,0 +1,34 @@
+//! This module provides platform-independent time types.
+//!
+//! `std::time::{Instant, SystemTime}` panic on `wasm32-unknown-unknown`, so the API-compatible
+//! types from `web-time` are used there instead.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+#[cfg(target_arch = "wasm32")]
+pub use web_time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// A source of the current time, used by every helper of the SDK that reads the current UNIX
+/// timestamp.
+///
+/// Elapsed durations, such as the age of cached responses or the time spent on a request, are
+/// measured with the monotonic [`Instant`] instead, so they are unaffected by the clock. The
+/// default [`SystemClock`] reads the system time. Custom implementations make
+/// time-windowed behavior deterministic in tests, or allow replaying data in simulated time.
+pub trait Clock: std::fmt::Debug + Send + Sync {
+    /// Returns the current UNIX timestamp in seconds.
+    fn now(&self) -> u64;
+}
+
+/// A [`Clock`] reading the system time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs())
+    }
+}
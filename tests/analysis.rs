@@ -0,0 +1,37 @@
+mod common;
+
+use cielo_rs_sdk::{
+    analysis::{validate_feed_with_clock, IssueKind},
+    models::feed::Item,
+    Clock,
+};
+
+/// A clock frozen at a fixed UNIX timestamp.
+#[derive(Debug)]
+struct FixedClock(u64);
+
+impl Clock for FixedClock {
+    fn now(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Test to ensure timestamps are checked against the provided clock.
+#[test]
+fn test_validate_feed_uses_clock() {
+    let item: Item = serde_json::from_value(common::item_fixtures().remove(0)).unwrap();
+    let timestamp = item.timestamp();
+    let items = [item];
+
+    let future_issues = |now| {
+        validate_feed_with_clock(&items, &FixedClock(now))
+            .into_iter()
+            .filter(|issue| matches!(issue.kind, IssueKind::TimestampOutOfRange(_)))
+            .count()
+    };
+
+    // Assert that the item is only flagged when it is more than a day ahead of the clock.
+    assert_eq!(future_issues(timestamp), 0);
+    assert_eq!(future_issues(timestamp - 60 * 60), 0);
+    assert_eq!(future_issues(timestamp - 2 * 24 * 60 * 60), 1);
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use cielo_rs_sdk::api::balances::BalanceFilters;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+/// Test to ensure a captured balances response is deserialized and the filters are sent.
+#[tokio::test]
+async fn test_get_balances() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/balances"))
+        .and(query_param(
+            "wallet",
+            "0x1111111111111111111111111111111111111111",
+        ))
+        .and(query_param("chains", "ethereum,base"))
+        .and(query_param("minUSD", "100"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(include_str!("fixtures/balances.json"), "application/json"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let filters = BalanceFilters {
+        chains: Some(vec!["ethereum".to_string(), "base".to_string()]),
+        min_usd: Some(100),
+    };
+    let balances = cielo_api
+        .get_balances("0x1111111111111111111111111111111111111111", filters)
+        .await
+        .unwrap();
+
+    // Assert that every balance was parsed.
+    assert_eq!(balances.len(), 2);
+    assert_eq!(balances[0].token_symbol, "ETH");
+    assert_eq!(balances[0].balance_usd, 3125.0);
+    assert_eq!(balances[1].chain, "base");
+}
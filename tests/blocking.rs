@@ -0,0 +1,32 @@
+#![cfg(feature = "blocking")]
+
+mod common;
+
+use cielo_rs_sdk::api;
+use serde_json::Value;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+/// Test to ensure the blocking API fetches the feed outside of an async context.
+#[test]
+fn test_blocking_get_feed() {
+    // The mock server needs a runtime, but the blocking call must be made outside of it.
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let server = runtime.block_on(MockServer::start());
+    let items: Vec<Value> = common::item_fixtures().into_iter().take(2).collect();
+    runtime.block_on(
+        Mock::given(method("GET"))
+            .and(path("/feed"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(items.into())))
+            .mount(&server),
+    );
+
+    let cielo_api = common::mock_client(&server);
+
+    let response = cielo_api.blocking_get_feed(api::feed::Filters::default());
+
+    // Assert that the feed data was fetched successfully.
+    assert_eq!(response.expect("Failed to fetch feed").len(), 2);
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "cancellation")]
+
+mod common;
+
+use std::time::{Duration, Instant};
+
+use cielo_rs_sdk::api;
+use serde_json::json;
+use tokio_util::sync::CancellationToken;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+/// Test to ensure cancelling mid-pagination promptly returns the items gathered so far.
+#[tokio::test]
+async fn test_get_feed_all_cancelled_returns_partial_results() {
+    let server = MockServer::start().await;
+    let fixtures = common::item_fixtures();
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("startFrom", "page2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(common::feed_body(json!([fixtures[1]])))
+                .set_delay(Duration::from_secs(5)),
+        )
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(common::feed_page(json!([fixtures[0]]), Some("page2"))),
+        )
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+    let cancellation = CancellationToken::new();
+    let canceller = cancellation.clone();
+    let options = api::feed::FeedAllOptions {
+        cancellation: Some(cancellation),
+        ..Default::default()
+    };
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        canceller.cancel();
+    });
+
+    let start = Instant::now();
+    let items = cielo_api
+        .get_feed_all(api::feed::Filters::default(), 10, options)
+        .await
+        .unwrap();
+
+    // Assert that the first page was returned without waiting for the slow second one.
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].tx_hash(), fixtures[0]["tx_hash"].as_str().unwrap());
+    assert!(start.elapsed() < Duration::from_secs(2));
+}
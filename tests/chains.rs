@@ -0,0 +1,42 @@
+mod common;
+
+use cielo_rs_sdk::CieloApi;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+/// Test to ensure a sample chains response is deserialized, and cached when enabled.
+#[tokio::test]
+async fn test_get_supported_chains() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/chains"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(include_str!("fixtures/chains.json"), "application/json"),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    // Assert that every chain was parsed, in order.
+    let chains = common::mock_client(&server)
+        .get_supported_chains()
+        .await
+        .unwrap();
+    assert_eq!(chains, ["ethereum", "solana", "base", "arbitrum", "bsc"]);
+
+    let cielo_api = CieloApi::builder("test_api_key")
+        .base_url(&server.uri())
+        .max_retries(0)
+        .cache_supported_chains(true)
+        .build()
+        .unwrap();
+
+    // Assert that the cached list is reused by the client and its clones.
+    let first = cielo_api.get_supported_chains().await.unwrap();
+    let second = cielo_api.clone().get_supported_chains().await.unwrap();
+    assert_eq!(first, chains);
+    assert_eq!(second, chains);
+}
@@ -1,4 +1,46 @@
+#![allow(dead_code)]
+
+use cielo_rs_sdk::CieloApi;
+use serde_json::{json, Value};
+use wiremock::MockServer;
+
 /// Sets up the test environment by loading environment variables from the .env.test file.
 pub fn setup() {
     dotenvy::from_filename(".env.test").ok();
 }
+
+/// Creates a CieloApi client pointed at the given mock server, with retries disabled.
+pub fn mock_client(server: &MockServer) -> CieloApi {
+    CieloApi::builder("test_api_key")
+        .base_url(&server.uri())
+        .max_retries(0)
+        .build()
+        .unwrap()
+}
+
+/// Wraps the given items in a successful feed response envelope without a next page.
+pub fn feed_body(items: Value) -> Value {
+    feed_page(items, None)
+}
+
+/// Wraps the given items in a successful feed response envelope, with an optional next page.
+pub fn feed_page(items: Value, next_object: Option<&str>) -> Value {
+    let total_rows_in_page = items.as_array().map_or(0, |items| items.len());
+    json!({
+        "status": "ok",
+        "data": {
+            "items": items,
+            "paging": {
+                "total_rows_in_page": total_rows_in_page,
+                "has_next_page": next_object.is_some(),
+                "next_object": next_object
+            }
+        },
+        "message": null
+    })
+}
+
+/// Returns one JSON fixture for each feed item variant, in the order of the `Item` enum.
+pub fn item_fixtures() -> Vec<Value> {
+    serde_json::from_str(include_str!("../fixtures/items.json")).unwrap()
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "polars")]
+
+mod common;
+
+use cielo_rs_sdk::{export, models::feed::Item};
+
+/// Test to ensure a data frame has one row per item and the flat columns, whatever the variants.
+#[test]
+fn test_to_dataframe() {
+    let fixtures = common::item_fixtures();
+    let items: Vec<Item> = [0, 1, 5, 9]
+        .into_iter()
+        .map(|i| serde_json::from_value(fixtures[i].clone()).unwrap())
+        .collect();
+
+    let df = export::to_dataframe(&items).unwrap();
+
+    // Assert that every item became a row with the same columns.
+    assert_eq!(df.height(), 4);
+    assert_eq!(
+        df.get_column_names_str(),
+        [
+            "wallet",
+            "wallet_label",
+            "tx_hash",
+            "tx_type",
+            "chain",
+            "timestamp",
+            "block",
+            "usd_value",
+            "counterparty",
+        ]
+    );
+
+    // Assert that values were copied, with nulls for missing ones.
+    let tx_types = df.column("tx_type").unwrap();
+    assert_eq!(tx_types.str().unwrap().get(2), Some("nft_trade"));
+    let usd_values = df.column("usd_value").unwrap();
+    assert_eq!(usd_values.f64().unwrap().get(1), Some(3.0));
+    assert_eq!(usd_values.null_count(), 1);
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "decimal")]
+
+mod common;
+
+use cielo_rs_sdk::api::feed::Filters;
+use cielo_rs_sdk::models::feed::{DecimalItem, Item};
+use rust_decimal::Decimal;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Test to ensure decimal amounts keep the digits written in the response and sum exactly.
+#[test]
+fn test_usd_value_decimal_is_exact() {
+    let transfer = |amount_usd: f64| {
+        let mut item = common::item_fixtures().remove(2);
+        item["amount_usd"] = amount_usd.into();
+        serde_json::from_value::<Item>(item).unwrap()
+    };
+    let items = [transfer(1_234_567.891_234_5), transfer(0.1), transfer(0.2)];
+
+    let f64_total: f64 = items.iter().filter_map(Item::usd_value).sum();
+    let decimal_total: Decimal = items.iter().filter_map(Item::usd_value_decimal).sum();
+
+    // Assert that the decimal sum is exact while the f64 one is not.
+    let expected: Decimal = "1234568.1912345".parse().unwrap();
+    assert_eq!(decimal_total, expected);
+    assert_ne!(f64_total.to_string(), expected.to_string());
+    assert_eq!(
+        items[0].usd_value_decimal(),
+        Some("1234567.8912345".parse().unwrap())
+    );
+}
+
+/// Test to ensure amounts with more digits than an `f64` holds are parsed as written.
+#[test]
+fn test_decimal_item_keeps_digits_beyond_f64() {
+    let mut swap = common::item_fixtures().remove(0);
+    swap["token0_amount"] = 0.into();
+    swap["token0_amount_usd"] = 0.into();
+    let body = common::feed_body(serde_json::json!([swap]))
+        .to_string()
+        .replace(
+            r#""token0_amount":0"#,
+            r#""token0_amount":123456789012.3456789012345"#,
+        )
+        .replace(
+            r#""token0_amount_usd":0"#,
+            r#""token0_amount_usd":9876543210.123456789"#,
+        );
+
+    let items = DecimalItem::from_feed_body(body.as_bytes()).unwrap();
+    let Item::Swap(swap) = items[0].item() else {
+        panic!("Expected a swap");
+    };
+
+    // Assert that all 25 and 19 significant digits were kept, unlike in the f64 fields.
+    let expected: Decimal = "123456789012.3456789012345".parse().unwrap();
+    assert_eq!(items[0].amount("token0_amount"), Some(expected));
+    assert_ne!(swap.token0_amount_decimal(), Some(expected));
+    assert_eq!(
+        items[0].usd_value(),
+        Some("9876543210.123456789".parse().unwrap())
+    );
+    assert_eq!(items[0].amount("tx_hash"), None);
+}
+
+/// Test to ensure exact decimals are fetched for each item of a feed page.
+#[tokio::test]
+async fn test_get_feed_decimal() {
+    let server = MockServer::start().await;
+    let mut transfer = common::item_fixtures().remove(2);
+    transfer["amount_usd"] = 0.into();
+    let body = common::feed_body(serde_json::json!([transfer]))
+        .to_string()
+        .replace(
+            r#""amount_usd":0"#,
+            r#""amount_usd":1234567890.12345678901"#,
+        );
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(body))
+        .mount(&server)
+        .await;
+
+    let items = common::mock_client(&server)
+        .get_feed_decimal(Filters::default())
+        .await
+        .unwrap();
+
+    // Assert that the item and its exact USD value were both parsed.
+    assert_eq!(items.len(), 1);
+    assert!(matches!(items[0].item(), Item::Transfer(_)));
+    assert_eq!(
+        items[0].usd_value(),
+        Some("1234567890.12345678901".parse().unwrap())
+    );
+}
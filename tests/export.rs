@@ -0,0 +1,25 @@
+#![cfg(feature = "msgpack")]
+
+mod common;
+
+use cielo_rs_sdk::{export, models::feed::Item};
+
+/// Test to ensure every item variant round-trips through MessagePack.
+#[test]
+fn test_msgpack_round_trip() {
+    for fixture in common::item_fixtures() {
+        let item: Item = serde_json::from_value(fixture).unwrap();
+
+        let bytes = export::items_to_msgpack(std::slice::from_ref(&item)).unwrap();
+        let decoded = export::items_from_msgpack(&bytes).unwrap();
+
+        // Assert that the decoded item is identical to the original one.
+        assert_eq!(decoded.len(), 1);
+        assert_eq!(
+            serde_json::to_value(&decoded[0]).unwrap(),
+            serde_json::to_value(&item).unwrap(),
+            "Item did not round-trip: {:?}",
+            item
+        );
+    }
+}
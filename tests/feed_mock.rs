@@ -0,0 +1,1887 @@
+mod common;
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use cielo_rs_sdk::{api, ApiStats, CieloApi, Error, RetryContext};
+use futures::{FutureExt, StreamExt};
+use serde_json::json;
+use wiremock::{
+    matchers::{header, method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
+
+/// Test to ensure a per-request timeout surfaces as `Error::Timeout`.
+#[tokio::test]
+async fn test_get_feed_with_timeout_elapses() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(common::feed_body(json!([])))
+                .set_delay(Duration::from_millis(500)),
+        )
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let response = cielo_api
+        .get_feed_with_timeout(api::feed::Filters::default(), Duration::from_millis(50))
+        .await;
+
+    // Assert that the deadline was reported as a timeout.
+    assert!(
+        matches!(response, Err(Error::Timeout { .. })),
+        "Expected a timeout error: {:?}",
+        response
+    );
+}
+
+/// Test to ensure `get_new_trades` requests new swap trades only.
+#[tokio::test]
+async fn test_get_new_trades_sets_query() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("newTrades", "true"))
+        .and(query_param("txTypes", "swap"))
+        .and(query_param("chains", "solana"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let response = cielo_api
+        .get_new_trades(None, Some(vec![api::feed::Chain::Solana]))
+        .await;
+
+    // Assert that the request matched the expected query string.
+    assert!(
+        response.is_ok(),
+        "Failed to fetch new trades: {:?}",
+        response.err()
+    );
+}
+
+/// Test to ensure token activity is fetched with the single token and the common parameters.
+#[tokio::test]
+async fn test_get_token_activity_sets_query() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("tokens", "BONK"))
+        .and(query_param("chains", "solana,base"))
+        .and(query_param("limit", "50"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let response = cielo_api
+        .get_token_activity(
+            "BONK",
+            Some(vec![api::feed::Chain::Solana, api::feed::Chain::Base]),
+            Some(50),
+        )
+        .await;
+
+    // Assert that the request matched the expected query string.
+    assert!(
+        response.is_ok(),
+        "Failed to fetch token activity: {:?}",
+        response.err()
+    );
+}
+
+/// Test to ensure the sort order is sent as a query parameter.
+#[tokio::test]
+async fn test_get_feed_sort_order() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("sort", "asc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let response = cielo_api
+        .get_feed(api::feed::Filters {
+            sort: Some(api::feed::SortOrder::Ascending),
+            ..Default::default()
+        })
+        .await;
+
+    // Assert that the request matched the expected query string.
+    assert!(
+        response.is_ok(),
+        "Failed to fetch feed: {:?}",
+        response.err()
+    );
+}
+
+/// Test to ensure the retry callback fires for every retryable attempt.
+#[tokio::test]
+async fn test_on_retry_callback() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(2)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([]))))
+        .mount(&server)
+        .await;
+
+    let retries = Arc::new(Mutex::new(Vec::new()));
+    let recorded = retries.clone();
+    let cielo_api = CieloApi::builder("test_api_key")
+        .base_url(&server.uri())
+        .min_retry_interval(1)
+        .max_retry_interval(10)
+        .max_retries(3)
+        .on_retry(move |context| recorded.lock().unwrap().push(context.clone()))
+        .build()
+        .unwrap();
+
+    let response = cielo_api
+        .get_feed_with_meta(api::feed::Filters::default())
+        .await;
+
+    // Assert that the request eventually succeeded after two retries.
+    let (_, meta) = response.expect("Failed to fetch feed");
+    assert_eq!(meta.attempts.len(), 3);
+    assert_eq!(meta.attempts[2].status, Some(200));
+    assert_eq!(
+        *retries.lock().unwrap(),
+        vec![
+            RetryContext {
+                attempt: 1,
+                status: Some(503)
+            },
+            RetryContext {
+                attempt: 2,
+                status: Some(503)
+            },
+        ]
+    );
+}
+
+/// Test to ensure the feed stream retries a failed page instead of leaving a gap.
+#[tokio::test]
+async fn test_get_feed_stream_retries_failed_page() {
+    let server = MockServer::start().await;
+    let fixtures = common::item_fixtures();
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("startFrom", "page2"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("startFrom", "page2"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(common::feed_body(json!([fixtures[1]]))),
+        )
+        .with_priority(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(common::feed_page(json!([fixtures[0]]), Some("page2"))),
+        )
+        .with_priority(3)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let items: Vec<_> = cielo_api
+        .get_feed_stream(api::feed::Filters::default(), 1)
+        .collect()
+        .await;
+
+    // Assert that both pages were streamed despite the failure.
+    assert_eq!(items.len(), 2);
+    assert!(items.iter().all(|item| item.is_ok()), "{:?}", items);
+}
+
+/// Test to ensure the feed stream does not retry pages failing with a non-transient error.
+#[tokio::test]
+async fn test_get_feed_stream_does_not_retry_unauthorized() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(401).set_body_string("invalid api key"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let items: Vec<_> = cielo_api
+        .get_feed_stream(api::feed::Filters::default(), 3)
+        .collect()
+        .await;
+
+    // Assert that the error was yielded after a single attempt.
+    assert_eq!(items.len(), 1);
+    assert!(
+        matches!(items[0], Err(Error::Unauthorized { .. })),
+        "{:?}",
+        items
+    );
+}
+
+/// Test to ensure `get_tx` finds a transaction on a later page.
+#[tokio::test]
+async fn test_get_tx_scans_pages() {
+    let server = MockServer::start().await;
+    let fixtures = common::item_fixtures();
+    let tx_hash = fixtures[1]["tx_hash"].as_str().unwrap().to_string();
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("startFrom", "page2"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(common::feed_body(json!([fixtures[1]]))),
+        )
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(common::feed_page(json!([fixtures[0]]), Some("page2"))),
+        )
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let item = cielo_api
+        .get_tx(&tx_hash)
+        .await
+        .expect("Failed to fetch tx");
+
+    // Assert that the matching item was returned.
+    assert_eq!(item.map(|item| item.tx_hash().to_string()), Some(tx_hash));
+}
+
+/// Test to ensure a rejected API key is refreshed through the key provider and the request retried.
+#[tokio::test]
+async fn test_key_provider_refreshes_on_unauthorized() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(header("X-API-KEY", "test_api_key"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(header("X-API-KEY", "rotated_api_key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([]))))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let cielo_api = CieloApi::builder("test_api_key")
+        .base_url(&server.uri())
+        .max_retries(0)
+        .key_provider(Arc::new(|| {
+            async { Ok("rotated_api_key".to_string()) }.boxed()
+        }))
+        .build()
+        .unwrap();
+
+    // The first request is refreshed, the second one reuses the new key.
+    for _ in 0..2 {
+        let response = cielo_api.get_feed(api::feed::Filters::default()).await;
+        assert!(
+            response.is_ok(),
+            "Expected a successful response: {:?}",
+            response
+        );
+    }
+}
+
+/// Test to ensure requests identify the SDK with a default User-Agent.
+#[tokio::test]
+async fn test_default_user_agent() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(header(
+            "User-Agent",
+            format!("cielo-rs-sdk/{}", env!("CARGO_PKG_VERSION")).as_str(),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let response = cielo_api.get_feed(api::feed::Filters::default()).await;
+
+    // Assert that the request matched the User-Agent header.
+    assert!(
+        response.is_ok(),
+        "Expected a successful response: {:?}",
+        response
+    );
+}
+
+/// Test to ensure the full envelope is returned, including the status, message and paging.
+#[tokio::test]
+async fn test_get_feed_response() {
+    let server = MockServer::start().await;
+    let mut body = common::feed_page(json!([common::item_fixtures()[0]]), Some("page2"));
+    body["message"] = "results may be delayed".into();
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let response = cielo_api
+        .get_feed_response(api::feed::Filters::default())
+        .await
+        .unwrap();
+
+    // Assert that the envelope fields are accessible alongside the items.
+    assert_eq!(response.status, "ok");
+    assert_eq!(response.message.as_deref(), Some("results may be delayed"));
+    assert_eq!(response.data.items.len(), 1);
+    assert!(response.data.paging.has_next_page);
+    assert_eq!(response.data.paging.next_object.as_deref(), Some("page2"));
+}
+
+/// Test to ensure additional headers reach the server, without replacing the API key.
+#[tokio::test]
+async fn test_custom_headers() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(header("X-Org-Id", "acme"))
+        .and(header("X-API-KEY", "test_api_key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = CieloApi::builder("test_api_key")
+        .base_url(&server.uri())
+        .max_retries(0)
+        .header("X-Org-Id", "acme")
+        .build()
+        .unwrap();
+
+    // Assert that the request matched both headers.
+    let response = cielo_api.get_feed(api::feed::Filters::default()).await;
+    assert!(
+        response.is_ok(),
+        "Expected a successful response: {:?}",
+        response
+    );
+
+    // Assert that the API key header cannot be set as an additional header.
+    let cielo_api = CieloApi::builder("test_api_key")
+        .header("x-api-key", "other_api_key")
+        .build();
+    assert!(
+        matches!(cielo_api, Err(Error::InvalidHeaderName(_))),
+        "Expected an invalid header name error: {:?}",
+        cielo_api
+    );
+}
+
+/// Test to ensure paging stops and truncates at the first item older than the floor.
+#[tokio::test]
+async fn test_get_feed_until_timestamp_truncates_at_floor() {
+    let server = MockServer::start().await;
+    let item = |timestamp: u64| {
+        let mut item = common::item_fixtures().remove(0);
+        item["timestamp"] = timestamp.into();
+        item
+    };
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("startFrom", "page2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_page(
+            json!([item(1_000), item(900), item(800)]),
+            Some("page3"),
+        )))
+        .expect(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_page(
+            json!([item(1_200), item(1_100)]),
+            Some("page2"),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let items = cielo_api
+        .get_feed_until_timestamp(api::feed::Filters::default(), 900, 5)
+        .await
+        .unwrap();
+
+    // Assert that the items older than the floor were dropped and the third page not fetched.
+    let timestamps: Vec<u64> = items.iter().map(|item| item.timestamp()).collect();
+    assert_eq!(timestamps, [1_200, 1_100, 1_000, 900]);
+}
+
+/// Test to ensure a backfill follows the cursor across pages and stops at the target timestamp.
+#[tokio::test]
+async fn test_backfill_until_crosses_pages() {
+    let server = MockServer::start().await;
+    let item = |timestamp: u64| {
+        let mut item = common::item_fixtures().remove(0);
+        item["timestamp"] = timestamp.into();
+        item
+    };
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("startFrom", "page3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_page(
+            json!([item(700), item(600)]),
+            Some("page4"),
+        )))
+        .expect(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("startFrom", "page2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_page(
+            json!([item(900), item(800)]),
+            Some("page3"),
+        )))
+        .expect(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_page(
+            json!([item(1_100), item(1_000)]),
+            Some("page2"),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let items = cielo_api
+        .backfill_until(api::feed::Filters::default(), 650)
+        .await
+        .unwrap();
+
+    // Assert that the pages were walked back to the target and the fourth page not fetched.
+    let timestamps: Vec<u64> = items.iter().map(|item| item.timestamp()).collect();
+    assert_eq!(timestamps, [1_100, 1_000, 900, 800, 700]);
+}
+
+/// Test to ensure a backfill requests newest-first pages even when the filters sort ascending.
+#[tokio::test]
+async fn test_backfill_until_overrides_ascending_sort() {
+    let server = MockServer::start().await;
+    let item = |timestamp: u64| {
+        let mut item = common::item_fixtures().remove(0);
+        item["timestamp"] = timestamp.into();
+        item
+    };
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("sort", "desc"))
+        .and(query_param("startFrom", "page2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_page(
+            json!([item(900), item(800)]),
+            Some("page3"),
+        )))
+        .expect(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("sort", "desc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_page(
+            json!([item(1_100), item(1_000)]),
+            Some("page2"),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+    let filters = api::feed::Filters {
+        sort: Some(api::feed::SortOrder::Ascending),
+        ..Default::default()
+    };
+
+    let items = cielo_api.backfill_until(filters, 850).await.unwrap();
+
+    // Assert that the pages were requested newest first and cut at the target.
+    let timestamps: Vec<u64> = items.iter().map(|item| item.timestamp()).collect();
+    assert_eq!(timestamps, [1_100, 1_000, 900]);
+}
+
+/// Test to ensure a body larger than the configured limit is rejected.
+#[tokio::test]
+async fn test_max_response_bytes_rejects_large_body() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(common::feed_body(json!(common::item_fixtures()))),
+        )
+        .mount(&server)
+        .await;
+
+    let cielo_api = CieloApi::builder("test_api_key")
+        .base_url(&server.uri())
+        .max_retries(0)
+        .max_response_bytes(1024)
+        .build()
+        .unwrap();
+
+    let response = cielo_api.get_feed(api::feed::Filters::default()).await;
+
+    // Assert that the body was rejected for its size.
+    assert!(
+        matches!(response, Err(Error::ResponseTooLarge(1024))),
+        "Expected a response too large error: {:?}",
+        response
+    );
+}
+
+/// Test to ensure retries stop once the total retry deadline would be exceeded.
+#[tokio::test]
+async fn test_total_retry_deadline_gives_up_promptly() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server)
+        .await;
+
+    let cielo_api = CieloApi::builder("test_api_key")
+        .base_url(&server.uri())
+        .min_retry_interval(200)
+        .max_retry_interval(1000)
+        .max_retries(10)
+        .total_retry_deadline(Duration::from_millis(300))
+        .build()
+        .unwrap();
+
+    let start = std::time::Instant::now();
+    let response = cielo_api.get_feed(api::feed::Filters::default()).await;
+
+    // Assert that the last error was returned long before all retries were exhausted. The
+    // backoff uses full jitter, so retries may start sooner than the minimum interval and the
+    // exact number of attempts within the deadline varies.
+    assert!(
+        matches!(response, Err(Error::StatusNot200 { .. })),
+        "Expected the last error: {:?}",
+        response
+    );
+    assert!(start.elapsed() < Duration::from_secs(2));
+    assert!(server.received_requests().await.unwrap().len() < 11);
+}
+
+/// Test to ensure the market cap parameter is sent only when requested.
+#[tokio::test]
+async fn test_get_feed_include_market_cap() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("includeMarketCap", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param_is_missing("includeMarketCap"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    // Assert that each request matched the mock expecting the parameter or its absence.
+    for include_market_cap in [Some(true), None] {
+        let response = cielo_api
+            .get_feed(api::feed::Filters {
+                include_market_cap,
+                ..Default::default()
+            })
+            .await;
+        assert!(
+            response.is_ok(),
+            "Failed to fetch feed: {:?}",
+            response.err()
+        );
+    }
+}
+
+/// Test to ensure an empty result for a wallet filter is flagged as possibly unwatched.
+#[tokio::test]
+async fn test_get_feed_checked_flags_unwatched_wallet() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([]))))
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let filtered = cielo_api
+        .get_feed_checked(api::feed::Filters {
+            wallet: Some("0x1111111111111111111111111111111111111111".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let unfiltered = cielo_api
+        .get_feed_checked(api::feed::Filters::default())
+        .await
+        .unwrap();
+
+    // Assert that only the wallet-filtered empty result carries the hint.
+    assert!(filtered.items.is_empty());
+    assert!(filtered.possibly_unwatched);
+    assert!(!unfiltered.possibly_unwatched);
+}
+
+/// Test to ensure the no-retry path returns the first failure instead of retrying.
+#[tokio::test]
+async fn test_get_feed_no_retry_fails_immediately() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([]))))
+        .mount(&server)
+        .await;
+
+    let cielo_api = CieloApi::builder("test_api_key")
+        .base_url(&server.uri())
+        .max_retries(3)
+        .build()
+        .unwrap();
+
+    let response = cielo_api
+        .get_feed_no_retry(api::feed::Filters::default())
+        .await;
+
+    // Assert that the 503 was returned after a single request.
+    assert!(
+        matches!(response, Err(Error::StatusNot200 { .. })),
+        "Expected the first failure: {:?}",
+        response
+    );
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+}
+
+/// Test to ensure strict mode rejects responses with inconsistent paging.
+#[tokio::test]
+async fn test_strict_responses_reject_inconsistent_paging() {
+    let server = MockServer::start().await;
+    let mut body = common::feed_page(json!([]), Some("next"));
+    body["data"]["paging"]["total_rows_in_page"] = json!(5);
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(&server)
+        .await;
+
+    let client = |strict_responses| {
+        CieloApi::builder("test_api_key")
+            .base_url(&server.uri())
+            .max_retries(0)
+            .strict_responses(strict_responses)
+            .build()
+            .unwrap()
+    };
+
+    let lenient = client(false).get_feed(api::feed::Filters::default()).await;
+    let strict = client(true).get_feed(api::feed::Filters::default()).await;
+
+    // Assert that the mismatch is only reported in strict mode.
+    assert!(lenient.is_ok(), "Failed to fetch feed: {:?}", lenient.err());
+    assert!(
+        matches!(strict, Err(Error::InvalidResponse(_))),
+        "Expected an invalid response error: {:?}",
+        strict
+    );
+}
+
+/// Test to ensure the newest item's timestamp is fetched with a single-item page.
+#[tokio::test]
+async fn test_latest_timestamp() {
+    let server = MockServer::start().await;
+    let mut item = common::item_fixtures().remove(0);
+    item["timestamp"] = json!(1_726_500_000);
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("limit", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([item]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let timestamp = cielo_api
+        .latest_timestamp(api::feed::Filters::default())
+        .await
+        .unwrap();
+
+    // Assert that the item's timestamp was returned.
+    assert_eq!(timestamp, Some(1_726_500_000));
+}
+
+/// Test to ensure extra parameters are sent verbatim, without overriding typed ones.
+#[tokio::test]
+async fn test_get_feed_extra_params() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("newParam", "42"))
+        .and(query_param("limit", "10"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let response = cielo_api
+        .get_feed(api::feed::Filters {
+            limit: Some(10),
+            extra: Some(vec![
+                ("newParam".to_string(), "42".to_string()),
+                ("limit".to_string(), "99".to_string()),
+            ]),
+            ..Default::default()
+        })
+        .await;
+
+    // Assert that the request matched the extra parameter and the typed limit.
+    assert!(
+        response.is_ok(),
+        "Failed to fetch feed: {:?}",
+        response.err()
+    );
+    let requests = server.received_requests().await.unwrap();
+    assert!(!requests[0].url.query().unwrap().contains("limit=99"));
+}
+
+/// Test to ensure an empty body is treated as no items rather than malformed data.
+#[tokio::test]
+async fn test_get_feed_empty_body() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let response = cielo_api.get_feed(api::feed::Filters::default()).await;
+
+    // Assert that no items were returned.
+    assert!(
+        matches!(&response, Ok(items) if items.is_empty()),
+        "Expected an empty feed: {:?}",
+        response
+    );
+}
+
+/// Test to ensure a failure propagates immediately when retries are disabled.
+#[tokio::test]
+async fn test_no_retry_propagates_failure() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([]))))
+        .mount(&server)
+        .await;
+
+    let cielo_api = CieloApi::builder("test_api_key")
+        .base_url(&server.uri())
+        .no_retry()
+        .build()
+        .unwrap();
+
+    let response = cielo_api.get_feed(api::feed::Filters::default()).await;
+
+    // Assert that the 503 was returned after a single request.
+    assert!(
+        matches!(response, Err(Error::StatusNot200 { .. })),
+        "Expected the first failure: {:?}",
+        response
+    );
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+}
+
+/// Test to ensure typed endpoints request a single transaction type and extract its items.
+#[tokio::test]
+async fn test_get_swaps_typed() {
+    let server = MockServer::start().await;
+    let fixtures = common::item_fixtures();
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("txTypes", "swap"))
+        .and(query_param("chains", "ethereum"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(common::feed_body(json!([
+                fixtures[0],
+                fixtures[2],
+                fixtures[0]
+            ]))),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("txTypes", "transfer"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(common::feed_body(json!([fixtures[0], fixtures[2]]))),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+    let filters = api::feed::Filters {
+        chains: Some(vec!["ethereum".to_string()]),
+        tx_types: Some(vec![api::feed::TxType::Transfer]),
+        ..Default::default()
+    };
+
+    // Assert that only items of the requested variant are returned.
+    let swaps = cielo_api.get_swaps(filters).await.unwrap();
+    assert_eq!(swaps.len(), 2);
+    assert_eq!(swaps[0].tx_hash, fixtures[0]["tx_hash"].as_str().unwrap());
+    let transfers = cielo_api
+        .get_transfers(api::feed::Filters::default())
+        .await
+        .unwrap();
+    assert_eq!(transfers.len(), 1);
+    assert_eq!(
+        transfers[0].tx_hash,
+        fixtures[2]["tx_hash"].as_str().unwrap()
+    );
+}
+
+/// Test to ensure the health check succeeds against a reachable API with a valid key.
+#[tokio::test]
+async fn test_health_check_succeeds() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("limit", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    // Assert that the probe passed.
+    cielo_api.health_check().await.unwrap();
+}
+
+/// Test to ensure the health check reports a rejected key as unauthorized.
+#[tokio::test]
+async fn test_health_check_unauthorized() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(403).set_body_string("invalid api key"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let response = cielo_api.health_check().await;
+
+    // Assert that the auth failure was distinguished and kept the body.
+    assert!(
+        matches!(&response, Err(Error::Unauthorized { body, .. }) if body == "invalid api key"),
+        "Expected an unauthorized error: {:?}",
+        response
+    );
+}
+
+/// Test to ensure the health check reports an unreachable API as a connection failure.
+#[tokio::test]
+async fn test_health_check_connection_error() {
+    // Nothing listens on the port once the listener is dropped.
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+
+    let cielo_api = CieloApi::builder("test_api_key")
+        .base_url(&format!("http://127.0.0.1:{}", port))
+        .build()
+        .unwrap();
+
+    let response = cielo_api.health_check().await;
+
+    // Assert that the connectivity failure was not mistaken for an auth failure.
+    assert!(
+        matches!(
+            response,
+            Err(Error::ReqwestMiddleware { .. }) | Err(Error::Reqwest { .. })
+        ),
+        "Expected a connection error: {:?}",
+        response
+    );
+}
+
+/// Test to ensure a status error carries the redacted URL, the status and the body.
+#[tokio::test]
+async fn test_get_feed_status_error_context() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(400).set_body_string("bad request"))
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let filters = api::feed::Filters {
+        limit: Some(5),
+        extra: Some(vec![("secret".to_string(), "hunter2".to_string())]),
+        ..Default::default()
+    };
+    let response = cielo_api.get_feed(filters).await;
+
+    // Assert that the failed request can be identified without leaking the secret.
+    let Err(Error::StatusNot200 {
+        status,
+        body,
+        context,
+    }) = response
+    else {
+        panic!("Expected a status error: {:?}", response);
+    };
+    let url = context.url.unwrap();
+    assert!(url.contains("/feed?limit=5"), "{}", url);
+    assert!(url.contains("secret=REDACTED"), "{}", url);
+    assert!(!url.contains("hunter2"), "{}", url);
+    assert_eq!(status, 400);
+    assert_eq!(body, "bad request");
+}
+
+/// Test to ensure only errors that may not recur on a retry are classified as transient.
+#[tokio::test]
+async fn test_error_is_transient() {
+    // Assert that an error building the request is fatal.
+    let builder_error = reqwest::Client::new().get("not a url").build().unwrap_err();
+    assert!(builder_error.is_builder());
+    assert!(!Error::from(builder_error).is_transient());
+
+    // Assert that an error decoding the response is fatal.
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("not json"))
+        .mount(&server)
+        .await;
+    let decode_error = reqwest::get(format!("{}/feed", server.uri()))
+        .await
+        .unwrap()
+        .json::<serde_json::Value>()
+        .await
+        .unwrap_err();
+    assert!(decode_error.is_decode());
+    assert!(!Error::from(decode_error).is_transient());
+
+    // Assert that a refused connection is transient.
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let cielo_api = CieloApi::builder("test_api_key")
+        .base_url(&format!("http://127.0.0.1:{}", port))
+        .max_retries(0)
+        .build()
+        .unwrap();
+    let error = cielo_api
+        .get_feed(api::feed::Filters::default())
+        .await
+        .unwrap_err();
+    assert!(error.is_transient(), "{:?}", error);
+}
+
+/// Test to ensure errors report their request context and source chain.
+#[tokio::test]
+async fn test_error_context_and_source() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(400).set_body_string("bad request"))
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+    let error = cielo_api
+        .get_feed(api::feed::Filters::default())
+        .await
+        .unwrap_err();
+
+    // Assert that the context is carried and displayed.
+    let context = error.context().unwrap();
+    assert_eq!(context.attempts, Some(1));
+    assert!(context.elapsed.is_some());
+    let message = error.to_string();
+    assert!(
+        message.starts_with("Response status 400: bad request ("),
+        "{}",
+        message
+    );
+    assert!(message.contains("/feed"), "{}", message);
+    assert!(message.contains("attempts: 1"), "{}", message);
+
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let cielo_api = CieloApi::builder("test_api_key")
+        .base_url(&format!("http://127.0.0.1:{}", port))
+        .max_retries(0)
+        .build()
+        .unwrap();
+    let error = cielo_api
+        .get_feed(api::feed::Filters::default())
+        .await
+        .unwrap_err();
+
+    // Assert that a transport error carries the context and reports its cause once, as source.
+    let context = error.context().unwrap();
+    assert!(
+        context.url.as_ref().unwrap().contains("/feed"),
+        "{:?}",
+        context
+    );
+    assert_eq!(context.attempts, Some(1));
+    let message = error.to_string();
+    let source = std::error::Error::source(&error).expect("Expected a source error");
+    assert!(message.contains("attempts: 1"), "{}", message);
+    assert!(!message.contains(&source.to_string()), "{}", message);
+}
+
+/// Test to ensure a successful response reporting an error in its body is rejected by default.
+#[tokio::test]
+async fn test_get_feed_error_status_in_body() {
+    let server = MockServer::start().await;
+    let mut body = common::feed_body(json!([common::item_fixtures()[0]]));
+    body["status"] = "error".into();
+    body["message"] = "internal error".into();
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(&server)
+        .await;
+
+    let response = common::mock_client(&server)
+        .get_feed(api::feed::Filters::default())
+        .await;
+
+    // Assert that the message of the response was surfaced.
+    assert!(
+        matches!(&response, Err(Error::Api(message)) if message == "internal error"),
+        "Expected an API error: {:?}",
+        response
+    );
+
+    let cielo_api = CieloApi::builder("test_api_key")
+        .base_url(&server.uri())
+        .max_retries(0)
+        .check_status(false)
+        .build()
+        .unwrap();
+
+    // Assert that lenient clients get the items anyway.
+    let items = cielo_api
+        .get_feed(api::feed::Filters::default())
+        .await
+        .unwrap();
+    assert_eq!(items.len(), 1);
+}
+
+/// Test to ensure the circuit breaker opens after repeated failures and recovers after cooldown.
+#[tokio::test]
+async fn test_circuit_breaker_opens_and_recovers() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server)
+        .await;
+
+    let cielo_api = CieloApi::builder("test_api_key")
+        .base_url(&server.uri())
+        .max_retries(0)
+        .circuit_breaker(2, Duration::from_millis(200))
+        .build()
+        .unwrap();
+    let get_feed = || cielo_api.get_feed(api::feed::Filters::default());
+
+    // Assert that the failures reach the server until the threshold is hit.
+    for _ in 0..2 {
+        let response = get_feed().await;
+        assert!(
+            matches!(response, Err(Error::StatusNot200 { .. })),
+            "Expected a status error: {:?}",
+            response
+        );
+    }
+
+    // Assert that the open breaker fails fast without a request.
+    let response = get_feed().await;
+    assert!(
+        matches!(response, Err(Error::CircuitOpen)),
+        "Expected an open circuit: {:?}",
+        response
+    );
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    server.reset().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([]))))
+        .expect(2)
+        .mount(&server)
+        .await;
+    tokio::time::sleep(Duration::from_millis(250)).await;
+
+    // Assert that a request succeeds after the cooldown and closes the breaker.
+    get_feed().await.unwrap();
+    get_feed().await.unwrap();
+}
+
+/// Test to ensure client errors show that the API is up and do not open the circuit breaker.
+#[tokio::test]
+async fn test_circuit_breaker_ignores_client_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(400).set_body_string("bad request"))
+        .expect(4)
+        .mount(&server)
+        .await;
+
+    let cielo_api = CieloApi::builder("test_api_key")
+        .base_url(&server.uri())
+        .max_retries(0)
+        .circuit_breaker(2, Duration::from_secs(60))
+        .build()
+        .unwrap();
+
+    // Assert that every request reaches the server past the threshold.
+    for _ in 0..4 {
+        let response = cielo_api.get_feed(api::feed::Filters::default()).await;
+        assert!(
+            matches!(response, Err(Error::StatusNot200 { status: 400, .. })),
+            "Expected a status error: {:?}",
+            response
+        );
+    }
+}
+
+/// Test to ensure a half-open circuit breaker lets a single probe request through.
+#[tokio::test]
+async fn test_circuit_breaker_single_probe() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(common::feed_body(json!([])))
+                .set_delay(Duration::from_millis(200)),
+        )
+        .mount(&server)
+        .await;
+
+    let cielo_api = CieloApi::builder("test_api_key")
+        .base_url(&server.uri())
+        .max_retries(0)
+        .circuit_breaker(1, Duration::from_millis(100))
+        .build()
+        .unwrap();
+    let get_feed = || cielo_api.get_feed(api::feed::Filters::default());
+    assert!(get_feed().await.is_err());
+    tokio::time::sleep(Duration::from_millis(150)).await;
+
+    let (probe, concurrent) = tokio::join!(get_feed(), async {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        get_feed().await
+    });
+
+    // Assert that the probe was sent while the concurrent request failed fast.
+    probe.unwrap();
+    assert!(
+        matches!(concurrent, Err(Error::CircuitOpen)),
+        "Expected an open circuit: {:?}",
+        concurrent
+    );
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    // Assert that the successful probe closed the breaker.
+    get_feed().await.unwrap();
+}
+
+/// Test to ensure a rejected API key surfaces as `Error::Unauthorized` with the response body.
+#[tokio::test]
+async fn test_get_feed_unauthorized() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(401).set_body_string("invalid api key"))
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let response = cielo_api.get_feed(api::feed::Filters::default()).await;
+
+    // Assert that the auth failure was not reported as a generic status error.
+    assert!(
+        matches!(&response, Err(Error::Unauthorized { body, .. }) if body == "invalid api key"),
+        "Expected an unauthorized error: {:?}",
+        response
+    );
+}
+
+/// Test to ensure statuses in the configured range are parsed as successful responses.
+#[tokio::test]
+async fn test_get_feed_accept_status() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(202)
+                .set_body_json(common::feed_body(json!([common::item_fixtures()[0]]))),
+        )
+        .mount(&server)
+        .await;
+
+    // Assert that the status is rejected by default.
+    let response = common::mock_client(&server)
+        .get_feed(api::feed::Filters::default())
+        .await;
+    assert!(
+        matches!(response, Err(Error::StatusNot200 { .. })),
+        "Expected a status error: {:?}",
+        response
+    );
+
+    let cielo_api = CieloApi::builder("test_api_key")
+        .base_url(&server.uri())
+        .max_retries(0)
+        .accept_status(200..=202)
+        .build()
+        .unwrap();
+
+    let items = cielo_api
+        .get_feed(api::feed::Filters::default())
+        .await
+        .unwrap();
+
+    // Assert that the body was parsed once the status is accepted.
+    assert_eq!(items.len(), 1);
+
+    // Assert that the raw body and health check paths accept the same statuses.
+    assert!(!cielo_api
+        .get_feed_bytes(api::feed::Filters::default())
+        .await
+        .unwrap()
+        .is_empty());
+    cielo_api.health_check().await.unwrap();
+}
+
+/// Test to ensure paging stops once the item cap is reached in the middle of a page.
+#[tokio::test]
+async fn test_get_feed_all_max_items() {
+    let server = MockServer::start().await;
+    let fixtures = common::item_fixtures();
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param_is_missing("startFrom"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_page(
+            json!([fixtures[0], fixtures[1]]),
+            Some("page2"),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("startFrom", "page2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_page(
+            json!([fixtures[2], fixtures[3]]),
+            Some("page3"),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let items = cielo_api
+        .get_feed_all(
+            api::feed::Filters::default(),
+            10,
+            api::feed::FeedAllOptions {
+                max_items: Some(3),
+                #[cfg(feature = "cancellation")]
+                cancellation: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Assert that exactly the cap was returned and the third page was never requested.
+    let tx_types: Vec<_> = items.iter().map(|item| item.tx_type_enum()).collect();
+    assert_eq!(
+        tx_types,
+        [
+            api::feed::TxType::Swap,
+            api::feed::TxType::Lp,
+            api::feed::TxType::Transfer
+        ]
+    );
+}
+
+/// Test to ensure pages are fetched one after another, merged and deduplicated.
+#[tokio::test]
+async fn test_get_feed_parallel_merges_pages() {
+    let server = MockServer::start().await;
+    let fixtures = common::item_fixtures();
+    for (start_from, items, next_object) in [
+        ("page2", json!([fixtures[0], fixtures[1]]), Some("page3")),
+        ("page3", json!([fixtures[2]]), Some("page4")),
+    ] {
+        Mock::given(method("GET"))
+            .and(path("/feed"))
+            .and(query_param("startFrom", start_from))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(common::feed_page(items, next_object)),
+            )
+            .with_priority(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param_is_missing("startFrom"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(common::feed_page(json!([fixtures[0]]), Some("page2"))),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let items = cielo_api
+        .get_feed_parallel(api::feed::Filters::default(), 3, 4)
+        .await
+        .unwrap();
+
+    // Assert that the repeated item was dropped and no page beyond the limit was requested.
+    let hashes: Vec<&str> = items.iter().map(|item| item.tx_hash()).collect();
+    let expected: Vec<&str> = fixtures[..3]
+        .iter()
+        .map(|fixture| fixture["tx_hash"].as_str().unwrap())
+        .collect();
+    assert_eq!(hashes, expected);
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+}
+
+/// Test to ensure offset pages are fetched concurrently, within the bound, and merged in order.
+#[tokio::test]
+async fn test_get_feed_parallel_offset_pages() {
+    let server = MockServer::start().await;
+    let fixtures = common::item_fixtures();
+    let delay = Duration::from_millis(150);
+    for (offset, items, has_next_page) in [
+        ("10", json!([fixtures[0], fixtures[1]]), true),
+        ("12", json!([fixtures[1], fixtures[2]]), true),
+        ("14", json!([fixtures[3]]), true),
+        ("16", json!([fixtures[4]]), false),
+    ] {
+        let mut body = common::feed_body(items);
+        body["data"]["paging"]["has_next_page"] = has_next_page.into();
+        Mock::given(method("GET"))
+            .and(path("/feed"))
+            .and(query_param("limit", "2"))
+            .and(query_param("offset", offset))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(body)
+                    .set_delay(delay),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let cielo_api = common::mock_client(&server);
+    let filters = api::feed::Filters {
+        limit: Some(2),
+        offset: Some(10),
+        ..Default::default()
+    };
+
+    let start = std::time::Instant::now();
+    let items = cielo_api.get_feed_parallel(filters, 4, 2).await.unwrap();
+    let elapsed = start.elapsed();
+
+    // Assert that the pages were merged in order without the repeated item.
+    let hashes: Vec<&str> = items.iter().map(|item| item.tx_hash()).collect();
+    let expected: Vec<&str> = fixtures[..5]
+        .iter()
+        .map(|fixture| fixture["tx_hash"].as_str().unwrap())
+        .collect();
+    assert_eq!(hashes, expected);
+
+    // Assert that two pages were in flight at a time: faster than serial, slower than unbounded.
+    assert!(elapsed >= delay * 2, "{:?}", elapsed);
+    assert!(
+        elapsed < delay * 4 - Duration::from_millis(50),
+        "{:?}",
+        elapsed
+    );
+}
+
+/// Test to ensure watching the feed emits an item appearing after the baseline exactly once.
+#[tokio::test]
+async fn test_watch_feed_emits_new_items_once() {
+    let server = MockServer::start().await;
+    let fixtures = common::item_fixtures();
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(common::feed_body(json!([fixtures[0]]))),
+        )
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(common::feed_body(json!([fixtures[1], fixtures[0]]))),
+        )
+        .with_priority(2)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+    let stream = cielo_api.watch_feed(
+        api::feed::Filters::default(),
+        Duration::from_millis(10),
+        api::feed::WatchOptions::default(),
+    );
+    futures::pin_mut!(stream);
+
+    // Assert that only the item of the second poll was emitted.
+    let item = stream.next().await.unwrap().unwrap();
+    assert_eq!(item.tx_hash(), fixtures[1]["tx_hash"].as_str().unwrap());
+
+    // Assert that later polls returning the same items emit nothing.
+    let next = tokio::time::timeout(Duration::from_millis(100), stream.next()).await;
+    assert!(next.is_err(), "Unexpected item: {:?}", next);
+}
+
+/// Test to ensure polling helpers bypass the response cache.
+#[tokio::test]
+async fn test_polling_bypasses_cache() {
+    let server = MockServer::start().await;
+    let fixtures = common::item_fixtures();
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(common::feed_body(json!([fixtures[0]]))),
+        )
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(common::feed_body(json!([fixtures[1], fixtures[0]]))),
+        )
+        .with_priority(2)
+        .mount(&server)
+        .await;
+
+    let cielo_api = CieloApi::builder("test_api_key")
+        .base_url(&server.uri())
+        .max_retries(0)
+        .cache_ttl(Duration::from_secs(60))
+        .build()
+        .unwrap();
+    let stream = cielo_api.watch_feed(
+        api::feed::Filters::default(),
+        Duration::from_millis(10),
+        api::feed::WatchOptions::default(),
+    );
+    futures::pin_mut!(stream);
+
+    // Assert that the second poll reached the server despite the longer TTL.
+    let item = stream.next().await.unwrap().unwrap();
+    assert_eq!(item.tx_hash(), fixtures[1]["tx_hash"].as_str().unwrap());
+
+    // Assert that the latest timestamp lookup reached the server too.
+    let requests = server.received_requests().await.unwrap().len();
+    cielo_api
+        .latest_timestamp(api::feed::Filters::default())
+        .await
+        .unwrap();
+    cielo_api
+        .latest_timestamp(api::feed::Filters::default())
+        .await
+        .unwrap();
+    assert_eq!(
+        server.received_requests().await.unwrap().len(),
+        requests + 2
+    );
+}
+
+/// Test to ensure the list feed sends the list and limit only.
+#[tokio::test]
+async fn test_get_list_feed_sets_query() {
+    let server = MockServer::start().await;
+    let mut mock = Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("list", "42"))
+        .and(query_param("limit", "20"));
+    for name in [
+        "wallet",
+        "chains",
+        "txTypes",
+        "tokens",
+        "minUSD",
+        "newTrades",
+    ] {
+        mock = mock.and(query_param_is_missing(name));
+    }
+    mock.respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let items = cielo_api.get_list_feed(42, Some(20)).await.unwrap();
+
+    // Assert that the request matched the list-only query.
+    assert!(items.is_empty());
+}
+
+/// Test to ensure refused and dropped connections are retried as transient failures.
+#[tokio::test]
+async fn test_connection_failures_are_retried() {
+    // A server that accepts connections and closes them before responding.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let dropping_uri = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            drop(stream);
+        }
+    });
+
+    // A port that refuses connections, as nothing listens on it once the listener is dropped.
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let refusing_uri = format!("http://127.0.0.1:{}", port);
+
+    for uri in [dropping_uri, refusing_uri] {
+        let retries = Arc::new(Mutex::new(Vec::new()));
+        let recorded = retries.clone();
+        let cielo_api = CieloApi::builder("test_api_key")
+            .base_url(&uri)
+            .min_retry_interval(1)
+            .max_retry_interval(10)
+            .max_retries(2)
+            .on_retry(move |context| recorded.lock().unwrap().push(context.clone()))
+            .build()
+            .unwrap();
+
+        let response = cielo_api.get_feed(api::feed::Filters::default()).await;
+
+        // Assert that every attempt was classified as transient before giving up.
+        assert!(response.is_err());
+        let retries = retries.lock().unwrap();
+        assert_eq!(retries.len(), 3, "{uri}: {:?}", response);
+        assert!(retries.iter().all(|context| context.status.is_none()));
+    }
+}
+
+/// Test to ensure the raw feed body is returned unchanged.
+#[tokio::test]
+async fn test_get_feed_bytes_returns_raw_body() {
+    let server = MockServer::start().await;
+    let body = serde_json::to_vec(&common::feed_body(json!([common::item_fixtures()[0]]))).unwrap();
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(body.clone()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    let bytes = cielo_api
+        .get_feed_bytes(api::feed::Filters::default())
+        .await
+        .unwrap();
+
+    // Assert that the body was not altered.
+    assert_eq!(bytes.as_ref(), body.as_slice());
+}
+
+/// Test to ensure the exact query string sent for fully-populated filters does not change.
+#[tokio::test]
+async fn test_feed_query_string_snapshot() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+    let filters = api::feed::Filters {
+        wallet: Some("0xabc".to_string()),
+        limit: Some(25),
+        list_id: Some(7),
+        chains: Some(vec!["ethereum".to_string(), "base".to_string()]),
+        tx_types: Some(vec![api::feed::TxType::Swap, api::feed::TxType::NftTrade]),
+        tokens: Some(vec!["USDC".to_string()]),
+        min_usd: Some(500),
+        new_trades: Some(true),
+        start_from: Some("next".to_string()),
+        offset: None,
+        from_timestamp: Some(1_700_000_000),
+        to_timestamp: Some(1_800_000_000),
+        include_market_cap: Some(true),
+        sort: Some(api::feed::SortOrder::Descending),
+        extra: None,
+    };
+
+    cielo_api.get_feed(filters).await.unwrap();
+
+    // Assert that the parameter names, order and encoding match the API reference.
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(
+        requests[0].url.query(),
+        Some(
+            "wallet=0xabc&limit=25&list=7&chains=ethereum%2Cbase&txTypes=swap%2Cnft_trade\
+             &tokens=USDC&minUSD=500&newTrades=true&startFrom=next&fromTimestamp=1700000000\
+             &toTimestamp=1800000000&includeMarketCap=true&sort=desc"
+        )
+    );
+}
+
+/// Test to ensure the offset is sent, and rejected alongside a cursor without a request.
+#[tokio::test]
+async fn test_get_feed_offset() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("offset", "200"))
+        .and(query_param_is_missing("startFrom"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    // Assert that the offset was sent as a query parameter.
+    let filters = api::feed::Filters {
+        offset: Some(200),
+        ..Default::default()
+    };
+    cielo_api.get_feed(filters.clone()).await.unwrap();
+
+    // Assert that combining the offset with a cursor is rejected.
+    let response = cielo_api
+        .get_feed(api::feed::Filters {
+            start_from: Some("next".to_string()),
+            ..filters
+        })
+        .await;
+    assert!(
+        matches!(response, Err(Error::InvalidFilter(_))),
+        "Expected an invalid filter error: {:?}",
+        response
+    );
+}
+
+/// Test to ensure identical requests within the cache TTL hit the network once.
+#[tokio::test]
+async fn test_cache_ttl_serves_repeated_requests() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(common::feed_body(json!([common::item_fixtures()[0]]))),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let cielo_api = CieloApi::builder("test_api_key")
+        .base_url(&server.uri())
+        .max_retries(0)
+        .cache_ttl(Duration::from_secs(60))
+        .build()
+        .unwrap();
+    let filters = api::feed::Filters {
+        limit: Some(10),
+        ..Default::default()
+    };
+
+    // Assert that the second identical call was served from the cache.
+    let first = cielo_api.get_feed(filters.clone()).await.unwrap();
+    let second = cielo_api.get_feed(filters).await.unwrap();
+    assert_eq!(first.len(), 1);
+    assert_eq!(second.len(), 1);
+
+    // Assert that different filters are not served from the cache.
+    cielo_api
+        .get_feed(api::feed::Filters::default())
+        .await
+        .unwrap();
+}
+
+/// Test to ensure the client counts its requests, retries and their outcomes.
+#[tokio::test]
+async fn test_stats_counts_requests() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([]))))
+        .mount(&server)
+        .await;
+
+    let cielo_api = CieloApi::builder("test_api_key")
+        .base_url(&server.uri())
+        .min_retry_interval(1)
+        .max_retry_interval(10)
+        .max_retries(2)
+        .build()
+        .unwrap();
+
+    cielo_api
+        .get_feed(api::feed::Filters::default())
+        .await
+        .unwrap();
+    cielo_api
+        .clone()
+        .get_feed_no_retry(api::feed::Filters::default())
+        .await
+        .unwrap();
+
+    // Assert that the retried call and the single-attempt call were both counted.
+    assert_eq!(
+        cielo_api.stats(),
+        ApiStats {
+            requests: 3,
+            successes: 2,
+            retries: 1,
+            errors: 1,
+        }
+    );
+}
@@ -0,0 +1,395 @@
+mod common;
+
+use std::{
+    collections::HashSet,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use cielo_rs_sdk::{
+    api::{
+        feed::{Address, Chain, FeedFilter, Filters, SortOrder, TokenFilter, TxType},
+        pnl::Timeframe,
+    },
+    models::feed::Item,
+    Clock, Error,
+};
+use serde_json::json;
+use strum::IntoEnumIterator;
+
+/// Test to ensure filters round-trip through JSON and unset fields are omitted.
+#[test]
+fn test_filters_json_round_trip() {
+    let filters = Filters {
+        wallet: Some("0x1111111111111111111111111111111111111111".to_string()),
+        limit: Some(50),
+        chains: Some(vec!["ethereum".to_string(), "base".to_string()]),
+        tx_types: Some(vec![TxType::Swap, TxType::NftTrade]),
+        min_usd: Some(1_000),
+        sort: Some(SortOrder::Ascending),
+        ..Default::default()
+    };
+
+    let value = serde_json::to_value(&filters).unwrap();
+
+    // Assert that only the set fields were serialized, with snake_case transaction types.
+    assert_eq!(
+        value,
+        json!({
+            "wallet": "0x1111111111111111111111111111111111111111",
+            "limit": 50,
+            "chains": ["ethereum", "base"],
+            "tx_types": ["swap", "nft_trade"],
+            "min_usd": 1000,
+            "sort": "asc"
+        })
+    );
+    assert_eq!(serde_json::from_value::<Filters>(value).unwrap(), filters);
+}
+
+/// Test to ensure muted wallets are dropped whether they own, send or receive the item.
+#[test]
+fn test_feed_filter_excludes_wallets() {
+    let item = |wallet: &str, from: &str, to: &str| {
+        let mut item = common::item_fixtures().remove(0);
+        item["wallet"] = wallet.into();
+        item["from"] = from.into();
+        item["to"] = to.into();
+        serde_json::from_value::<Item>(item).unwrap()
+    };
+    let muted = "0xAAAA000000000000000000000000000000000000";
+    let filter = FeedFilter {
+        exclude_wallets: Some(HashSet::from([muted.to_string()])),
+    };
+
+    let items = filter.apply(vec![
+        item(&muted.to_lowercase(), "0xb", "0xc"),
+        item("0xb", muted, "0xc"),
+        item("0xb", "0xc", muted),
+        item("0xb", "0xc", "0xd"),
+    ]);
+
+    // Assert that only the item not involving the muted wallet was kept.
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].wallet(), "0xb");
+}
+
+/// Test to ensure parsing a transaction type is the inverse of displaying it.
+#[test]
+fn test_tx_type_display_from_str_round_trip() {
+    for tx_type in TxType::iter() {
+        assert_eq!(tx_type.to_string().parse::<TxType>(), Ok(tx_type));
+    }
+
+    // Assert that the API names are used and unknown names are rejected.
+    assert_eq!("nft_trade".parse::<TxType>(), Ok(TxType::NftTrade));
+    assert!("NftTrade".parse::<TxType>().is_err());
+}
+
+/// Test to ensure addresses are validated for their chain and usable in filters.
+#[test]
+fn test_address_parse() {
+    let evm = "0xd8da6bf26964af9d7eed9e03e53415d37aa96045";
+    let solana = "So11111111111111111111111111111111111111112";
+
+    // Assert that well-formed addresses are accepted and kept as given.
+    let wallet = Address::parse(Chain::Base, evm).unwrap();
+    assert_eq!(wallet.as_str(), evm);
+    assert_eq!(wallet.chain(), &Chain::Base);
+    assert!(Address::parse(Chain::Solana, solana).is_ok());
+
+    // Assert that malformed addresses and addresses of another chain are rejected.
+    for (chain, address) in [
+        (Chain::Ethereum, "0xd8da6bf26964af9d7eed9e03e53415d37aa9604"),
+        (
+            Chain::Ethereum,
+            "0xzz8da6bf26964af9d7eed9e03e53415d37aa9604",
+        ),
+        (Chain::Ethereum, solana),
+        (Chain::Solana, "So1111111111111111111111111111"),
+        (Chain::Solana, "So0000000000000000000000000000000000000000O"),
+        (Chain::Solana, evm),
+    ] {
+        assert!(
+            matches!(Address::parse(chain, address), Err(Error::InvalidFilter(_))),
+            "Expected {:?} to be rejected",
+            address
+        );
+    }
+
+    // Assert that parsed addresses can be used as the wallet and token filters.
+    let filters = Filters {
+        chains: Some(vec!["solana".to_string()]),
+        ..Default::default()
+    }
+    .wallet_address(&Address::parse(Chain::Solana, solana).unwrap())
+    .token_filters(vec![Address::parse(Chain::Solana, solana).unwrap().into()])
+    .unwrap();
+    assert_eq!(filters.wallet.as_deref(), Some(solana));
+    assert_eq!(filters.tokens, Some(vec![solana.to_string()]));
+}
+
+/// Test to ensure transaction types and chains can be used in sets.
+#[test]
+fn test_tx_type_and_chain_in_sets() {
+    let tx_types: HashSet<TxType> = [TxType::Swap, TxType::NftTrade, TxType::Swap].into();
+    let chains: HashSet<Chain> = ["ethereum", "base", "hyperevm"]
+        .into_iter()
+        .map(|chain| chain.parse().unwrap())
+        .collect();
+
+    // Assert that duplicates were merged and membership is checked by value.
+    assert_eq!(tx_types.len(), 2);
+    assert!(tx_types.contains(&TxType::NftTrade));
+    assert!(!tx_types.contains(&TxType::Transfer));
+    assert!(chains.contains(&Chain::Ethereum));
+    assert!(chains.contains(&Chain::Other("hyperevm".to_string())));
+    assert!(!chains.contains(&Chain::Solana));
+}
+
+/// Test to ensure the query pairs of a populated filter match the API parameter names.
+#[test]
+fn test_to_query_pairs() {
+    let filters = Filters {
+        wallet: Some("0xabc".to_string()),
+        limit: Some(25),
+        list_id: Some(7),
+        chains: Some(vec!["ethereum".to_string(), "base".to_string()]),
+        tx_types: Some(vec![TxType::Swap, TxType::NftTrade]),
+        tokens: Some(vec!["USDC".to_string()]),
+        min_usd: Some(500),
+        new_trades: Some(true),
+        start_from: Some("next".to_string()),
+        offset: Some(40),
+        from_timestamp: Some(1_700_000_000),
+        to_timestamp: Some(1_800_000_000),
+        include_market_cap: Some(true),
+        sort: Some(SortOrder::Descending),
+        extra: None,
+    };
+
+    let pairs = filters.to_query_pairs();
+
+    // Assert that every field was mapped to its parameter, in order.
+    let expected = [
+        ("wallet", "0xabc"),
+        ("limit", "25"),
+        ("list", "7"),
+        ("chains", "ethereum,base"),
+        ("txTypes", "swap,nft_trade"),
+        ("tokens", "USDC"),
+        ("minUSD", "500"),
+        ("newTrades", "true"),
+        ("startFrom", "next"),
+        ("offset", "40"),
+        ("fromTimestamp", "1700000000"),
+        ("toTimestamp", "1800000000"),
+        ("includeMarketCap", "true"),
+        ("sort", "desc"),
+    ];
+    let pairs: Vec<(&str, &str)> = pairs
+        .iter()
+        .map(|(name, value)| (name.as_str(), value.as_str()))
+        .collect();
+    assert_eq!(pairs, expected);
+}
+
+/// Test to ensure token filters are validated per chain and sent in the tokens parameter.
+#[test]
+fn test_token_filters() {
+    let usdc = TokenFilter::Address("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48".to_string());
+    let ethereum = || Filters {
+        chains: Some(vec!["ethereum".to_string()]),
+        ..Default::default()
+    };
+
+    let filters = ethereum()
+        .token_filters(vec![usdc.clone(), TokenFilter::Symbol("PEPE".to_string())])
+        .unwrap();
+
+    // Assert that addresses and symbols were joined into the tokens parameter.
+    assert!(filters.to_query_pairs().contains(&(
+        "tokens".to_string(),
+        "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48,PEPE".to_string()
+    )));
+
+    // Assert that garbage and addresses of another chain were rejected.
+    let solana_address =
+        TokenFilter::Address("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string());
+    for token in [
+        TokenFilter::Address("not an address".to_string()),
+        TokenFilter::Address("0x1234".to_string()),
+        TokenFilter::Symbol("".to_string()),
+        solana_address.clone(),
+    ] {
+        assert!(matches!(
+            ethereum().token_filters(vec![token]),
+            Err(Error::InvalidFilter(_))
+        ));
+    }
+    assert!(Filters::default()
+        .token_filters(vec![solana_address, usdc])
+        .is_ok());
+}
+
+/// Test to ensure each PnL timeframe serializes to the value expected by the API.
+#[test]
+fn test_timeframe_serialization() {
+    let cases = [
+        (Timeframe::Day, "1d"),
+        (Timeframe::Week, "7d"),
+        (Timeframe::Month, "30d"),
+        (Timeframe::Year, "1y"),
+        (Timeframe::Max, "max"),
+        (Timeframe::Other("90d".to_string()), "90d"),
+    ];
+
+    // Assert that each variant displays, serializes and parses back consistently.
+    for (timeframe, value) in cases {
+        assert_eq!(timeframe.to_string(), value);
+        assert_eq!(serde_json::to_value(&timeframe).unwrap(), json!(value));
+        assert_eq!(value.parse::<Timeframe>(), Ok(timeframe));
+    }
+}
+
+/// Test to ensure relative time filters compute the start of the window from the current time.
+#[test]
+fn test_relative_time_filters() {
+    let now = || {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    };
+    let duration = Duration::from_secs(15 * 60);
+
+    let before = now();
+    let filters = Filters::default().since(duration);
+    let after = now();
+
+    // Assert that the start of the window is `now - duration`, within the time the call took.
+    let from_timestamp = filters.from_timestamp.unwrap();
+    assert!((before - 900..=after - 900).contains(&from_timestamp));
+
+    // Assert that only `within_last` clears the end of the window.
+    let bounded = Filters {
+        to_timestamp: Some(before),
+        ..Default::default()
+    };
+    assert_eq!(bounded.clone().since(duration).to_timestamp, Some(before));
+    assert_eq!(bounded.within_last(duration).to_timestamp, None);
+}
+
+/// A clock frozen at a fixed UNIX timestamp.
+#[derive(Debug)]
+struct FixedClock(u64);
+
+impl Clock for FixedClock {
+    fn now(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Test to ensure relative time filters compute the window from the provided clock.
+#[test]
+fn test_relative_time_filters_with_clock() {
+    let clock = FixedClock(1_726_000_000);
+    let duration = Duration::from_secs(15 * 60);
+
+    let filters = Filters::default().since_with_clock(duration, &clock);
+    let window = Filters {
+        to_timestamp: Some(1_726_000_500),
+        ..Default::default()
+    }
+    .within_last_with_clock(duration, &clock);
+
+    // Assert that the window starts exactly `duration` before the clock's time.
+    assert_eq!(filters.from_timestamp, Some(1_725_999_100));
+    assert_eq!(window.from_timestamp, Some(1_725_999_100));
+    assert_eq!(window.to_timestamp, None);
+}
+
+/// Test to ensure each preset sets its documented fields only.
+#[test]
+fn test_presets() {
+    let full_page = |tx_types: Vec<TxType>| Filters {
+        limit: Some(100),
+        tx_types: Some(tx_types),
+        ..Default::default()
+    };
+
+    // Assert that each preset matches its use case.
+    assert_eq!(
+        Filters::preset_smart_swaps(),
+        Filters {
+            min_usd: Some(1_000),
+            ..full_page(vec![TxType::Swap])
+        }
+    );
+    assert_eq!(
+        Filters::preset_large_transfers(),
+        Filters {
+            min_usd: Some(10_000),
+            ..full_page(vec![TxType::Transfer])
+        }
+    );
+    assert_eq!(
+        Filters::preset_new_trades(),
+        Filters {
+            new_trades: Some(true),
+            ..full_page(vec![TxType::Swap])
+        }
+    );
+    assert_eq!(
+        Filters::preset_nft_trades(),
+        full_page(vec![TxType::NftTrade, TxType::NftSweep])
+    );
+
+    // Assert that presets can be refined further.
+    let filters = Filters {
+        chains: Some(vec!["solana".to_string()]),
+        ..Filters::preset_smart_swaps()
+    }
+    .within_last(Duration::from_secs(60));
+    assert_eq!(filters.min_usd, Some(1_000));
+    assert_eq!(filters.chains, Some(vec!["solana".to_string()]));
+    assert!(filters.from_timestamp.is_some());
+}
+
+/// Test to ensure the canonical query identifies logically equal queries and only those.
+#[test]
+fn test_canonical_query() {
+    let filters = Filters {
+        chains: Some(vec!["solana".to_string(), "ethereum".to_string()]),
+        limit: Some(10),
+        ..Default::default()
+    };
+    let reordered = Filters {
+        chains: Some(vec![
+            "ethereum".to_string(),
+            "solana".to_string(),
+            "solana".to_string(),
+        ]),
+        ..filters.clone()
+    };
+
+    // Assert that the parameters are sorted by name and list values sorted and deduplicated.
+    assert_eq!(
+        filters.canonical_query(),
+        "chains=ethereum,solana&limit=10&"
+    );
+    assert_eq!(reordered.canonical_query(), filters.canonical_query());
+    assert_eq!(reordered.cache_key(), filters.cache_key());
+
+    // Assert that delimiters within values cannot make distinct queries look alike.
+    let spliced = Filters {
+        wallet: Some("a&limit=10".to_string()),
+        ..Default::default()
+    };
+    let split = Filters {
+        wallet: Some("a".to_string()),
+        limit: Some(10),
+        ..Default::default()
+    };
+    assert_eq!(spliced.canonical_query(), "wallet=a%26limit%3D10&");
+    assert_ne!(spliced.canonical_query(), split.canonical_query());
+}
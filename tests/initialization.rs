@@ -21,3 +21,35 @@ async fn test_cielo_api_initialization() {
         cielo_api.err()
     );
 }
+
+/// Test to ensure an empty or blank API key is rejected before any request is made.
+#[test]
+fn test_cielo_api_rejects_blank_api_key() {
+    for api_key in ["", "   "] {
+        let cielo_api = CieloApi::new(api_key, None, None, None);
+
+        // Assert that the API key was rejected.
+        assert!(
+            matches!(cielo_api, Err(cielo_rs_sdk::Error::InvalidApiKey(_))),
+            "Expected an invalid API key error for {:?}: {:?}",
+            api_key,
+            cielo_api
+        );
+    }
+}
+
+/// Test to ensure a client with custom connection pool settings builds successfully.
+#[test]
+fn test_cielo_api_custom_pool_settings() {
+    let cielo_api = CieloApi::builder("test_api_key")
+        .pool_max_idle_per_host(2)
+        .pool_idle_timeout(std::time::Duration::from_secs(120))
+        .build();
+
+    // Assert that the client was built.
+    assert!(
+        cielo_api.is_ok(),
+        "Failed to initialize CieloApi: {:?}",
+        cielo_api.err()
+    );
+}
@@ -0,0 +1,722 @@
+mod common;
+
+use std::collections::{HashMap, HashSet};
+
+use cielo_rs_sdk::{
+    api::feed::TxType,
+    models::{
+        feed::{
+            addresses_for_symbol, avg_value_by_type, count_by_tx_type, display_name,
+            filter_by_chain_min_usd, filter_by_market_cap, filter_self_transfers, group_by_tx_hash,
+            merge_sorted, net_token_flow, only_lps, only_nft_trades, only_swaps, only_transfers,
+            BridgeDirection, Direction, FlatItem, Item, LpAction, NftEvent, OptionDirection,
+            OptionPositionStatus, PerpDirection, StakeAction, Swap, TokenMarketCap, TokenStandard,
+            WrapAction,
+        },
+        ResponseData,
+    },
+};
+use serde_json::json;
+
+/// Test to ensure the average USD value is computed per transaction type.
+#[test]
+fn test_avg_value_by_type() {
+    let fixtures = common::item_fixtures();
+    let mut swap = fixtures[0].clone();
+    swap["token0_amount_usd"] = 3.5.into();
+
+    let items: Vec<Item> = [fixtures[0].clone(), swap, fixtures[1].clone()]
+        .into_iter()
+        .map(|item| serde_json::from_value(item).unwrap())
+        .collect();
+
+    let averages = avg_value_by_type(&items);
+
+    // Assert that swaps average their first token values and LPs sum both tokens.
+    assert_eq!(averages.len(), 2);
+    assert_eq!(averages[&TxType::Swap], 2.5);
+    assert_eq!(averages[&TxType::Lp], 3.0);
+}
+
+/// Test to ensure a deserialized swap compares equal to the expected value, field by field.
+#[test]
+fn test_swap_equality() {
+    let expected = Swap {
+        wallet: "0x1111111111111111111111111111111111111111".to_string(),
+        wallet_label: "alice.eth".to_string(),
+        tx_hash: "0x0000000000000000000000000000000000000000000000000000000000000001".to_string(),
+        tx_type: "swap".to_string(),
+        chain: "ethereum".to_string(),
+        index: 0,
+        timestamp: 1726000000,
+        block: 20000000,
+        dex: "dex_value".to_string(),
+        from: "0x1111111111111111111111111111111111111111".to_string(),
+        to: "0x2222222222222222222222222222222222222222".to_string(),
+        token0_address: "token0_address_value".to_string(),
+        token0_amount: 1.5,
+        token0_amount_usd: 1.5,
+        token0_name: "token0_name_value".to_string(),
+        token0_price_usd: 1.5,
+        token0_symbol: "token0_symbol_value".to_string(),
+        token0_icon_link: "token0_icon_link_value".to_string(),
+        token1_address: "token1_address_value".to_string(),
+        token1_amount: 1.5,
+        token1_amount_usd: 1.5,
+        token1_name: "token1_name_value".to_string(),
+        token1_price_usd: 1.5,
+        token1_symbol: "token1_symbol_value".to_string(),
+        token1_icon_link: "token1_icon_link_value".to_string(),
+        first_interaction: false,
+        token_market_cap: Some(TokenMarketCap {
+            token_address: "token_address_value".to_string(),
+            market_cap: Some(1000000.0),
+            liquidity: Some(50000.0),
+        }),
+    };
+
+    let item: Item = serde_json::from_value(common::item_fixtures().remove(0)).unwrap();
+
+    // Assert that the whole item matches, and that a single differing field breaks equality.
+    assert_eq!(item, Item::Swap(expected.clone()));
+    let Item::Swap(mut swap) = item else {
+        panic!("Expected a swap");
+    };
+    swap.token1_amount = 2.0;
+    assert_ne!(swap, expected);
+}
+
+/// Test to ensure items are counted per transaction type.
+#[test]
+fn test_count_by_tx_type() {
+    let fixtures = common::item_fixtures();
+    let items: Vec<Item> = [0, 2, 0, 5, 0, 2]
+        .into_iter()
+        .map(|i| serde_json::from_value(fixtures[i].clone()).unwrap())
+        .collect();
+
+    let counts = count_by_tx_type(&items);
+
+    // Assert that each type present is counted and absent types are left out.
+    assert_eq!(counts.len(), 3);
+    assert_eq!(counts[&TxType::Swap], 3);
+    assert_eq!(counts[&TxType::Transfer], 2);
+    assert_eq!(counts[&TxType::NftTrade], 1);
+    assert!(!counts.contains_key(&TxType::Lp));
+}
+
+/// Test to ensure items are grouped by transaction hash and ordered by index within a group.
+#[test]
+fn test_group_by_tx_hash() {
+    let fixtures = common::item_fixtures();
+    let tx_hash = fixtures[0]["tx_hash"].as_str().unwrap();
+    let mut swap = fixtures[0].clone();
+    swap["index"] = 3.into();
+    let mut transfer = fixtures[2].clone();
+    transfer["tx_hash"] = tx_hash.into();
+    transfer["index"] = 1.into();
+    let items: Vec<Item> = [swap, fixtures[5].clone(), transfer]
+        .into_iter()
+        .map(|item| serde_json::from_value(item).unwrap())
+        .collect();
+
+    let groups = group_by_tx_hash(items);
+
+    // Assert that the shared transaction is grouped and its items are ordered by index.
+    assert_eq!(groups.len(), 2);
+    let group = &groups[tx_hash];
+    assert_eq!(group.len(), 2);
+    assert!(matches!(group[0], Item::Transfer(_)));
+    assert!(matches!(group[1], Item::Swap(_)));
+    assert_eq!(groups[fixtures[5]["tx_hash"].as_str().unwrap()].len(), 1);
+}
+
+/// Test to ensure swaps are netted per wallet and token, selling token0 and buying token1.
+#[test]
+fn test_net_token_flow() {
+    let swap = |token0: &str, token0_amount: f64, token1: &str, token1_amount: f64| {
+        let mut swap = common::item_fixtures().remove(0);
+        swap["token0_address"] = token0.into();
+        swap["token0_amount"] = token0_amount.into();
+        swap["token1_address"] = token1.into();
+        swap["token1_amount"] = token1_amount.into();
+        serde_json::from_value::<Item>(swap).unwrap()
+    };
+    let wallet = common::item_fixtures()[0]["wallet"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    // Buy 100 USDC for 1 WETH, then sell 40 USDC for 0.5 WETH.
+    let items = [
+        swap("weth", 1.0, "usdc", 100.0),
+        swap("usdc", 40.0, "weth", 0.5),
+    ];
+
+    let flows = net_token_flow(&items);
+
+    // Assert that each token's amounts were netted with the expected signs.
+    assert_eq!(flows.len(), 2);
+    assert_eq!(flows[&(wallet.clone(), "weth".to_string())], -0.5);
+    assert_eq!(flows[&(wallet, "usdc".to_string())], 60.0);
+}
+
+/// Test to ensure a mixed vector yields only the requested concrete structs.
+#[test]
+fn test_only_variants() {
+    let fixtures = common::item_fixtures();
+    // Swap, LP, transfer, lending and NFT trade, with the swap repeated.
+    let items: Vec<Item> = [0, 1, 2, 3, 5, 0]
+        .into_iter()
+        .map(|i| serde_json::from_value(fixtures[i].clone()).unwrap())
+        .collect();
+
+    // Assert that each helper returned its variant only, in order.
+    let swaps = only_swaps(&items);
+    assert_eq!(swaps.len(), 2);
+    assert_eq!(swaps[0].tx_hash, items[0].tx_hash());
+    assert_eq!(swaps[1].tx_hash, items[5].tx_hash());
+    assert_eq!(only_lps(&items)[0].tx_hash, items[1].tx_hash());
+    assert_eq!(only_transfers(&items)[0].tx_hash, items[2].tx_hash());
+    assert_eq!(only_nft_trades(&items)[0].tx_hash, items[4].tx_hash());
+    assert_eq!(only_lps(&items).len(), 1);
+    assert_eq!(only_transfers(&items).len(), 1);
+    assert_eq!(only_nft_trades(&items).len(), 1);
+}
+
+/// Test to ensure counterparties are displayed by label, falling back to a shortened address.
+#[test]
+fn test_counterparty_display() {
+    let mut transfer = common::item_fixtures().remove(2);
+    transfer["from_label"] = "vitalik.eth".into();
+    transfer["to_label"] = "".into();
+    let Item::Transfer(transfer) = serde_json::from_value(transfer).unwrap() else {
+        panic!("Expected a transfer");
+    };
+
+    // Assert that the label is preferred and an empty one falls back to the address.
+    assert_eq!(
+        transfer.counterparty_display(Direction::From),
+        "vitalik.eth"
+    );
+    assert_eq!(
+        transfer.counterparty_display(Direction::To),
+        format!(
+            "{}…{}",
+            &transfer.to[..6],
+            &transfer.to[transfer.to.len() - 4..]
+        )
+    );
+    assert_eq!(display_name("  ", "short"), "short");
+}
+
+/// Test to ensure action strings parse to their typed variants, keeping unknown ones.
+#[test]
+fn test_typed_actions() {
+    // Assert that known values map to variants and display back to the raw string.
+    assert_eq!(BridgeDirection::from("deposit"), BridgeDirection::Deposit);
+    assert_eq!(LpAction::from("remove"), LpAction::Remove);
+    assert_eq!(WrapAction::from("unwrap").to_string(), "unwrap");
+    assert_eq!(
+        serde_json::from_value::<StakeAction>(json!("stake")).unwrap(),
+        StakeAction::Stake
+    );
+
+    // Assert that unknown values are kept rather than rejected.
+    assert_eq!(
+        serde_json::from_value::<StakeAction>(json!("restake")).unwrap(),
+        StakeAction::Other("restake".to_string())
+    );
+    assert_eq!(LpAction::from("Add").as_str(), "Add");
+
+    let mut lp = common::item_fixtures().remove(1);
+    lp["type"] = "add".into();
+    let Item::Lp(lp) = serde_json::from_value(lp).unwrap() else {
+        panic!("Expected an LP transaction");
+    };
+    assert_eq!(lp.lp_action(), LpAction::Add);
+}
+
+/// Test to ensure token standards parse on every variant carrying one, keeping unknown ones.
+#[test]
+fn test_token_standard() {
+    // Assert that each known standard maps to its variant and unknown ones are kept.
+    for (value, expected) in [
+        ("ERC20", TokenStandard::Erc20),
+        ("ERC721", TokenStandard::Erc721),
+        ("ERC1155", TokenStandard::Erc1155),
+        ("SPL", TokenStandard::Spl),
+        ("TRC20", TokenStandard::Other("TRC20".to_string())),
+    ] {
+        assert_eq!(
+            serde_json::from_value::<TokenStandard>(json!(value)).unwrap(),
+            expected
+        );
+        assert_eq!(expected.as_str(), value);
+    }
+
+    let fixtures = common::item_fixtures();
+    let item = |i: usize, field: &str, value: &str| {
+        let mut item = fixtures[i].clone();
+        item[field] = value.into();
+        serde_json::from_value::<Item>(item).unwrap()
+    };
+
+    // Assert that the accessors read the standard from the right field.
+    let Item::Transfer(transfer) = item(2, "type", "ERC20") else {
+        panic!("Expected a transfer");
+    };
+    assert_eq!(transfer.token_standard(), TokenStandard::Erc20);
+    let Item::NftMint(mint) = item(4, "contract_type", "ERC721") else {
+        panic!("Expected an NFT mint");
+    };
+    assert_eq!(mint.token_standard(), TokenStandard::Erc721);
+    let Item::NftTransfer(nft_transfer) = item(6, "contract_type", "ERC1155") else {
+        panic!("Expected an NFT transfer");
+    };
+    assert_eq!(nft_transfer.token_standard(), TokenStandard::Erc1155);
+    let Item::Wrap(wrap) = item(10, "token_type", "SPL") else {
+        panic!("Expected a wrap");
+    };
+    assert_eq!(wrap.token_standard(), TokenStandard::Spl);
+}
+
+/// Test to ensure perp directions parse and the PnL is totaled, with unrealized PnL optional.
+#[test]
+fn test_perp_helpers() {
+    let perp = |trade_direction: &str, unrealized_pnl: serde_json::Value| {
+        let mut perp = common::item_fixtures().remove(14);
+        perp["trade_direction"] = trade_direction.into();
+        perp["unrealized_pnl"] = unrealized_pnl;
+        let Item::Perp(perp) = serde_json::from_value(perp).unwrap() else {
+            panic!("Expected a perpetual trade");
+        };
+        perp
+    };
+
+    // Assert that long and short trades are told apart, and unknown ones are not long.
+    let long = perp("long", json!(2.5));
+    assert_eq!(long.perp_direction(), PerpDirection::Long);
+    assert!(long.is_long());
+    let short = perp("short", json!(2.5));
+    assert_eq!(short.perp_direction(), PerpDirection::Short);
+    assert!(!short.is_long());
+    assert!(!perp("hedge", json!(2.5)).is_long());
+
+    // Assert that the unrealized PnL is added when present and counted as zero otherwise.
+    assert_eq!(long.total_pnl(), 4.0);
+    assert_eq!(perp("long", serde_json::Value::Null).total_pnl(), 1.5);
+}
+
+/// Test to ensure option directions and position statuses parse, keeping unknown ones.
+#[test]
+fn test_option_direction() {
+    let option = |direction: &str| {
+        let mut option = common::item_fixtures().remove(18);
+        option["direction"] = direction.into();
+        option["position_status"] = "open".into();
+        let Item::Option(option) = serde_json::from_value(option).unwrap() else {
+            panic!("Expected an option event");
+        };
+        option
+    };
+
+    // Assert that calls and puts map to variants.
+    assert_eq!(option("call").option_direction(), OptionDirection::Call);
+    assert_eq!(option("put").option_direction(), OptionDirection::Put);
+    assert_eq!(
+        option("put").option_position_status(),
+        OptionPositionStatus::Open
+    );
+
+    // Assert that an unknown direction is kept rather than rejected.
+    assert_eq!(
+        option("straddle").option_direction(),
+        OptionDirection::Other("straddle".to_string())
+    );
+    assert_eq!(
+        serde_json::from_value::<OptionDirection>(json!("call")).unwrap(),
+        OptionDirection::Call
+    );
+}
+
+/// Test to ensure pages are merged newest first, keeping the page order for equal timestamps.
+#[test]
+fn test_merge_sorted() {
+    let item = |tx_hash: &str, timestamp: u64| {
+        let mut item = common::item_fixtures().remove(0);
+        item["tx_hash"] = tx_hash.into();
+        item["timestamp"] = timestamp.into();
+        serde_json::from_value::<Item>(item).unwrap()
+    };
+    let pages = vec![
+        vec![item("a", 300), item("b", 200), item("c", 100)],
+        vec![item("d", 250), item("e", 200), item("a", 300)],
+    ];
+
+    let hashes = |items: Vec<Item>| -> Vec<String> {
+        items
+            .iter()
+            .map(|item| item.tx_hash().to_string())
+            .collect()
+    };
+
+    // Assert that ties keep the page order and duplicates are dropped only when requested.
+    assert_eq!(
+        hashes(merge_sorted(pages.clone(), false)),
+        ["a", "a", "d", "b", "e", "c"]
+    );
+    assert_eq!(hashes(merge_sorted(pages, true)), ["a", "d", "b", "e", "c"]);
+}
+
+/// Test to ensure each chain's threshold applies, with the default for unlisted chains.
+#[test]
+fn test_filter_by_chain_min_usd() {
+    let transfer = |chain: &str, amount_usd: f64| {
+        let mut item = common::item_fixtures().remove(2);
+        item["chain"] = chain.into();
+        item["amount_usd"] = amount_usd.into();
+        serde_json::from_value::<Item>(item).unwrap()
+    };
+    let items = vec![
+        transfer("ethereum", 40.0),
+        transfer("ethereum", 60.0),
+        transfer("solana", 4.0),
+        transfer("solana", 6.0),
+        transfer("base", 15.0),
+        transfer("base", 25.0),
+    ];
+    let thresholds = HashMap::from([("ethereum".to_string(), 50.0), ("solana".to_string(), 5.0)]);
+
+    let items = filter_by_chain_min_usd(items, &thresholds, 20.0);
+
+    // Assert that only the items at or above their chain's minimum were kept.
+    let kept: Vec<(&str, Option<f64>)> = items
+        .iter()
+        .map(|item| (item.chain(), item.usd_value()))
+        .collect();
+    assert_eq!(
+        kept,
+        [
+            ("ethereum", Some(60.0)),
+            ("solana", Some(6.0)),
+            ("base", Some(25.0))
+        ]
+    );
+}
+
+/// Test to ensure items of several variants flatten to the common shape.
+#[test]
+fn test_flat_item_from_item() {
+    let fixtures = common::item_fixtures();
+    let item = |i: usize| serde_json::from_value::<Item>(fixtures[i].clone()).unwrap();
+    let to = fixtures[0]["to"].as_str().unwrap();
+
+    // Assert that the swap's fields were copied, with the recipient as counterparty.
+    let swap = FlatItem::from(&item(0));
+    assert_eq!(swap.wallet, fixtures[0]["wallet"].as_str().unwrap());
+    assert_eq!(swap.tx_hash, fixtures[0]["tx_hash"].as_str().unwrap());
+    assert_eq!(swap.tx_type, TxType::Swap);
+    assert_eq!(swap.timestamp, fixtures[0]["timestamp"].as_u64().unwrap());
+    assert_eq!(swap.block, fixtures[0]["block"].as_u64());
+    assert_eq!(swap.usd_value, Some(1.5));
+    assert_eq!(swap.counterparty.as_deref(), Some(to));
+
+    // Assert that LP transactions have no recipient and contract interactions no USD value.
+    let lp = FlatItem::from(&item(1));
+    assert_eq!(lp.tx_type, TxType::Lp);
+    assert_eq!(lp.usd_value, Some(3.0));
+    assert_eq!(lp.counterparty, None);
+    let interaction = FlatItem::from(&item(9));
+    assert_eq!(interaction.tx_type, TxType::ContractInteraction);
+    assert_eq!(interaction.usd_value, None);
+    assert_eq!(interaction.counterparty.as_deref(), Some(to));
+}
+
+/// Test to ensure the normalized JSON has the same keys for every variant.
+#[test]
+fn test_to_normalized_json() {
+    let fixtures = common::item_fixtures();
+    let keys = [
+        "type",
+        "wallet",
+        "wallet_label",
+        "tx_hash",
+        "chain",
+        "timestamp",
+        "block",
+        "usd_value",
+        "counterparty",
+    ];
+
+    for (i, tx_type) in [
+        (0, "swap"),
+        (1, "lp"),
+        (5, "nft_trade"),
+        (9, "contract_interaction"),
+    ] {
+        let item: Item = serde_json::from_value(fixtures[i].clone()).unwrap();
+        let normalized = item.to_normalized_json();
+        let object = normalized.as_object().unwrap();
+
+        // Assert that exactly the normalized keys are present, whatever the variant.
+        assert_eq!(object.len(), keys.len(), "{}", tx_type);
+        for key in keys {
+            assert!(object.contains_key(key), "{} is missing {}", tx_type, key);
+        }
+        assert_eq!(normalized["type"], tx_type);
+        assert_eq!(normalized["tx_hash"], fixtures[i]["tx_hash"]);
+    }
+}
+
+/// Test to ensure transfers between a user's own wallets are dropped, and external ones kept.
+#[test]
+fn test_filter_self_transfers() {
+    let fixtures = common::item_fixtures();
+    let transfer = |i: usize, from: &str, to: &str| {
+        let mut item = fixtures[i].clone();
+        item["from"] = from.into();
+        item["to"] = to.into();
+        serde_json::from_value::<Item>(item).unwrap()
+    };
+    let own = "0x1111111111111111111111111111111111111111";
+    let other_own = "0xAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA";
+    let external = "0x3333333333333333333333333333333333333333";
+    let own_wallets: HashSet<String> = [own.to_string(), other_own.to_string()].into();
+
+    let items = vec![
+        transfer(2, own, &other_own.to_lowercase()),
+        transfer(2, own, external),
+        transfer(6, other_own, own),
+        transfer(6, external, own),
+        transfer(2, external, external),
+        transfer(0, own, other_own),
+    ];
+
+    let kept: Vec<(TxType, String)> = filter_self_transfers(items, &own_wallets)
+        .iter()
+        .map(|item| (item.tx_type_enum(), item.from().to_string()))
+        .collect();
+
+    // Assert that only external transfers and other types remain, in order.
+    assert_eq!(
+        kept,
+        vec![
+            (TxType::Transfer, own.to_string()),
+            (TxType::NftTransfer, external.to_string()),
+            (TxType::Swap, own.to_string()),
+        ]
+    );
+}
+
+/// Test to ensure the first interaction flag is read from swaps and NFT trades.
+#[test]
+fn test_is_first_interaction() {
+    let fixtures = common::item_fixtures();
+    let item = |i: usize, first_interaction: bool| {
+        let mut item = fixtures[i].clone();
+        item["first_interaction"] = first_interaction.into();
+        serde_json::from_value::<Item>(item).unwrap()
+    };
+
+    // Assert that the flag is reported for the variants carrying it.
+    assert!(item(0, true).is_first_interaction());
+    assert!(!item(0, false).is_first_interaction());
+    assert!(item(5, true).is_first_interaction());
+    assert!(!item(5, false).is_first_interaction());
+
+    // Assert that other variants are never first interactions.
+    let transfer: Item = serde_json::from_value(fixtures[2].clone()).unwrap();
+    assert!(!transfer.is_first_interaction());
+}
+
+/// Test to ensure swaps and transfers are filtered by market cap, with missing data configurable.
+#[test]
+fn test_filter_by_market_cap() {
+    let fixtures = common::item_fixtures();
+    let mut microcap = fixtures[0].clone();
+    microcap["token_market_cap"]["market_cap"] = 5000.0.into();
+    let mut unknown = fixtures[2].clone();
+    unknown["token_market_cap"] = serde_json::Value::Null;
+    let mut partial = fixtures[2].clone();
+    partial["token_market_cap"]["market_cap"] = serde_json::Value::Null;
+    let items: Vec<Item> = [&fixtures[0], &microcap, &unknown, &partial, &fixtures[1]]
+        .into_iter()
+        .map(|item| serde_json::from_value(item.clone()).unwrap())
+        .collect();
+
+    let kinds =
+        |items: Vec<Item>| -> Vec<TxType> { items.iter().map(Item::tx_type_enum).collect() };
+
+    // Assert that the microcap swap is dropped and the LP transaction kept.
+    assert_eq!(
+        kinds(filter_by_market_cap(items.clone(), 100_000.0, true)),
+        vec![TxType::Swap, TxType::Transfer, TxType::Transfer, TxType::Lp]
+    );
+
+    // Assert that the transfers without market cap data are dropped on request.
+    assert_eq!(
+        kinds(filter_by_market_cap(items, 100_000.0, false)),
+        vec![TxType::Swap, TxType::Lp]
+    );
+}
+
+/// Test to ensure a market cap with null fields deserializes instead of failing the whole item.
+#[test]
+fn test_token_market_cap_partially_null() {
+    let token_market_cap: TokenMarketCap = serde_json::from_value(json!({
+        "token_address": "token_address_value",
+        "market_cap": 1000000.0,
+        "liquidity": null
+    }))
+    .unwrap();
+
+    // Assert that the known value is kept and the null one is absent.
+    assert_eq!(token_market_cap.market_cap, Some(1000000.0));
+    assert_eq!(token_market_cap.liquidity, None);
+}
+
+/// Test to ensure the data field deserializes from both the object and the bare array shape.
+#[test]
+fn test_response_data_shapes() {
+    let fixtures = common::item_fixtures();
+
+    // Assert that the object shape keeps its paging information.
+    let object: ResponseData<Item> = serde_json::from_value(
+        common::feed_page(json!([fixtures[0]]), Some("next"))["data"].clone(),
+    )
+    .unwrap();
+    assert_eq!(object.items.len(), 1);
+    assert!(object.paging.has_next_page);
+    assert_eq!(object.paging.next_object.as_deref(), Some("next"));
+
+    // Assert that a bare array is read as a single, complete page.
+    let array: ResponseData<Item> =
+        serde_json::from_value(json!([fixtures[0], fixtures[2]])).unwrap();
+    assert_eq!(array.items.len(), 2);
+    assert_eq!(array.paging.total_rows_in_page, 2);
+    assert!(!array.paging.has_next_page);
+    assert_eq!(array.paging.next_object, None);
+}
+
+/// Test to ensure the variant predicates and accessors match only their own variant.
+#[test]
+fn test_item_variant_methods() {
+    let fixtures = common::item_fixtures();
+    let swap: Item = serde_json::from_value(fixtures[0].clone()).unwrap();
+    let transfer: Item = serde_json::from_value(fixtures[2].clone()).unwrap();
+
+    // Assert that the swap is recognized as a swap only.
+    assert!(swap.is_swap());
+    assert!(!swap.is_transfer());
+    assert_eq!(
+        swap.as_swap().map(|swap| swap.tx_hash.as_str()),
+        fixtures[0]["tx_hash"].as_str()
+    );
+    assert!(swap.as_transfer().is_none());
+
+    // Assert that the transfer is recognized as a transfer only.
+    assert!(transfer.is_transfer());
+    assert!(!transfer.is_swap() && !transfer.is_nft_trade());
+    assert_eq!(
+        transfer.as_transfer().map(|transfer| transfer.amount_usd),
+        Some(1.5)
+    );
+    assert!(transfer.as_swap().is_none());
+}
+
+/// Test to ensure payloads fitting several variants land in the one named by their `tx_type`.
+#[test]
+fn test_item_dispatches_on_tx_type() {
+    let fixtures = common::item_fixtures();
+
+    // Assert that the payloads shadowed by earlier variants are dispatched on their `tx_type`.
+    let nft_sweep: Item = serde_json::from_value(fixtures[19].clone()).unwrap();
+    assert!(nft_sweep.is_nft_sweep(), "{:?}", nft_sweep);
+    let flashloan: Item = serde_json::from_value(fixtures[15].clone()).unwrap();
+    assert!(flashloan.is_flashloan(), "{:?}", flashloan);
+
+    // Assert that a payload identical except for its `tx_type` lands in the other variant.
+    let mut nft_trade = fixtures[19].clone();
+    nft_trade["tx_type"] = "nft_trade".into();
+    let nft_trade: Item = serde_json::from_value(nft_trade).unwrap();
+    assert!(nft_trade.is_nft_trade(), "{:?}", nft_trade);
+
+    // Assert that an unknown `tx_type` falls back to the declaration order.
+    let mut unknown = fixtures[19].clone();
+    unknown["tx_type"] = "nft_bulk_buy".into();
+    let unknown: Item = serde_json::from_value(unknown).unwrap();
+    assert!(unknown.is_nft_trade(), "{:?}", unknown);
+}
+
+/// Test to ensure every NFT variant exposes its NFT details, and other variants none.
+#[test]
+fn test_as_nft_event() {
+    let fixtures = common::item_fixtures();
+
+    // NFT mint, trade, transfer, lending, liquidation and sweep, with their address fields.
+    for (i, address_field, token_id_field) in [
+        (4, "contract_address", "nft_token_id"),
+        (5, "nft_address", "nft_token_id"),
+        (6, "contract_address", "nft_token_id"),
+        (7, "nft_address", "nft_token_id"),
+        (17, "nft_address", "token_id"),
+        (19, "nft_address", "nft_token_id"),
+    ] {
+        let item: Item = serde_json::from_value(fixtures[i].clone()).unwrap();
+        let nft: &dyn NftEvent = item.as_nft_event().expect("Expected an NFT event");
+
+        // Assert that the details were taken from the variant's fields.
+        assert_eq!(Some(nft.nft_address()), fixtures[i][address_field].as_str());
+        assert_eq!(
+            Some(nft.nft_token_id()),
+            fixtures[i][token_id_field].as_str()
+        );
+        assert_eq!(Some(nft.nft_name()), fixtures[i]["nft_name"].as_str());
+        assert_eq!(nft.image(), fixtures[i]["image"].as_str());
+    }
+
+    // Assert that other variants are not NFT events.
+    let swap: Item = serde_json::from_value(fixtures[0].clone()).unwrap();
+    assert!(swap.as_nft_event().is_none());
+}
+
+/// Test to ensure tokens sharing a symbol are told apart by chain and address.
+#[test]
+fn test_addresses_for_symbol() {
+    let fixtures = common::item_fixtures();
+    let mut ethereum_swap = fixtures[0].clone();
+    ethereum_swap["token1_symbol"] = "PEPE".into();
+    ethereum_swap["token1_address"] = "0x6982508145454ce325ddbe47a25d4ec3d2311933".into();
+    let mut repeated_swap = ethereum_swap.clone();
+    repeated_swap["token1_address"] = "0x6982508145454Ce325dDbE47a25d4ec3d2311933".into();
+    let mut solana_transfer = fixtures[2].clone();
+    solana_transfer["chain"] = "solana".into();
+    solana_transfer["symbol"] = "pepe".into();
+    solana_transfer["contract_address"] = "B5WTLaRwaUQpKk7ir1wniNB6m5o8GgMrimhKMYan2R6B".into();
+    let items: Vec<Item> = [
+        ethereum_swap,
+        fixtures[1].clone(),
+        repeated_swap,
+        solana_transfer,
+    ]
+    .into_iter()
+    .map(|item| serde_json::from_value(item).unwrap())
+    .collect();
+
+    // Assert that both tokens were found once each, in order.
+    assert_eq!(
+        addresses_for_symbol(&items, "PEPE"),
+        vec![
+            (
+                "ethereum".to_string(),
+                "0x6982508145454ce325ddbe47a25d4ec3d2311933".to_string()
+            ),
+            (
+                "solana".to_string(),
+                "B5WTLaRwaUQpKk7ir1wniNB6m5o8GgMrimhKMYan2R6B".to_string()
+            ),
+        ]
+    );
+    assert!(addresses_for_symbol(&items, "WIF").is_empty());
+}
@@ -0,0 +1,36 @@
+mod common;
+
+use cielo_rs_sdk::{
+    export,
+    models::feed::{self, Item},
+};
+
+/// Test to ensure items are written one per line and read back unchanged.
+#[test]
+fn test_to_ndjson_round_trip() {
+    let items: Vec<Item> = common::item_fixtures()
+        .into_iter()
+        .take(3)
+        .map(|fixture| serde_json::from_value(fixture).unwrap())
+        .collect();
+
+    let mut buffer = Vec::new();
+    feed::to_ndjson(&items, &mut buffer).unwrap();
+
+    // Assert that every item was written on its own line.
+    let output = String::from_utf8(buffer).unwrap();
+    assert_eq!(output.lines().count(), items.len());
+    assert!(output.ends_with('\n'));
+
+    // Assert that each line parses back to the original item.
+    let decoded: Vec<Item> = export::read_feed_ndjson(output.as_bytes())
+        .collect::<Result<_, _>>()
+        .unwrap();
+    for (decoded, item) in decoded.iter().zip(&items) {
+        assert_eq!(
+            serde_json::to_value(decoded).unwrap(),
+            serde_json::to_value(item).unwrap()
+        );
+    }
+    assert_eq!(decoded.len(), items.len());
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "rate-limit")]
+
+mod common;
+
+use std::{
+    num::NonZeroU32,
+    time::{Duration, Instant},
+};
+
+use cielo_rs_sdk::{api, CieloApi};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+/// Test to ensure rapid calls are spaced out according to the configured rate.
+#[tokio::test]
+async fn test_rate_limit_spaces_requests() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(json!([]))))
+        .expect(5)
+        .mount(&server)
+        .await;
+
+    let cielo_api = CieloApi::builder("test_api_key")
+        .base_url(&server.uri())
+        .max_retries(0)
+        .rate_limit(NonZeroU32::new(20).unwrap())
+        .build()
+        .unwrap();
+
+    let start = Instant::now();
+    for _ in 0..5 {
+        cielo_api
+            .get_feed(api::feed::Filters::default())
+            .await
+            .unwrap();
+    }
+
+    // Assert that the four requests after the first waited 50ms each.
+    assert!(
+        start.elapsed() >= Duration::from_millis(190),
+        "Requests were not spaced out: {:?}",
+        start.elapsed()
+    );
+}
@@ -0,0 +1,12 @@
+use cielo_rs_sdk::{models::feed::Item, CieloApi, Error};
+
+/// Compiles only if `T` can be shared and moved across threads.
+fn assert_send_sync<T: Send + Sync + 'static>() {}
+
+/// Test to ensure the client, errors and items can be shared across tasks.
+#[test]
+fn test_public_types_are_send_sync() {
+    assert_send_sync::<CieloApi>();
+    assert_send_sync::<Error>();
+    assert_send_sync::<Item>();
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "tracing")]
+
+mod common;
+
+use cielo_rs_sdk::api;
+use serde_json::Value;
+use tracing_test::traced_test;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+/// Test to ensure the `get_feed` span records the number of items parsed.
+#[tokio::test]
+#[traced_test]
+async fn test_get_feed_span_records_item_count() {
+    let server = MockServer::start().await;
+    let items: Vec<Value> = common::item_fixtures().into_iter().take(2).collect();
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::feed_body(items.into())))
+        .mount(&server)
+        .await;
+
+    let cielo_api = common::mock_client(&server);
+
+    cielo_api
+        .get_feed(api::feed::Filters::default())
+        .await
+        .expect("Failed to fetch feed");
+
+    // Assert that the span recorded the item count and never the API key.
+    assert!(logs_contain("items=2"));
+    assert!(logs_contain("status=200"));
+    assert!(!logs_contain("test_api_key"));
+}